@@ -0,0 +1,56 @@
+use std::fmt::{self, Display};
+use std::io;
+
+use brace_data_store::error::{Error as StoreError, ErrorKind};
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Json(serde_json::Error),
+    Csv(csv::Error),
+    Header,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Io(err) => err.fmt(f),
+            Self::Json(err) => err.fmt(f),
+            Self::Csv(err) => err.fmt(f),
+            Self::Header => f.write_str("CSV header does not match the item fields"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Json(error)
+    }
+}
+
+impl From<csv::Error> for Error {
+    fn from(error: csv::Error) -> Self {
+        Self::Csv(error)
+    }
+}
+
+impl From<Error> for StoreError {
+    fn from(error: Error) -> Self {
+        let kind = match &error {
+            Error::Io(_) => ErrorKind::Backend,
+            Error::Json(err) if err.is_io() => ErrorKind::Backend,
+            Error::Csv(err) if err.is_io_error() => ErrorKind::Backend,
+            Error::Json(_) | Error::Csv(_) | Error::Header => ErrorKind::Serialization,
+        };
+
+        StoreError::from_source(kind, error)
+    }
+}
@@ -0,0 +1,257 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::iter::once;
+
+use csv::StringRecord;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value as Json};
+
+use brace_data_store::{Row, Value};
+
+use crate::error::Error;
+
+pub type Items<'a, T> = Box<dyn Iterator<Item = Result<T, Error>> + 'a>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Lines,
+    Csv,
+}
+
+impl Format {
+    pub fn items<'a, R, T>(self, reader: R) -> Items<'a, T>
+    where
+        R: Read + 'a,
+        T: DeserializeOwned + 'a,
+    {
+        match self {
+            Self::Lines => Box::new(
+                BufReader::new(reader)
+                    .lines()
+                    .filter_map(|line| match line {
+                        Ok(line) if line.trim().is_empty() => None,
+                        Ok(line) => Some(serde_json::from_str(&line).map_err(Error::from)),
+                        Err(err) => Some(Err(err.into())),
+                    }),
+            ),
+            Self::Csv => Box::new(
+                csv::Reader::from_reader(reader)
+                    .into_deserialize()
+                    .map(|item| item.map_err(Error::from)),
+            ),
+        }
+    }
+
+    pub fn rows<'a, R>(self, reader: R) -> Items<'a, Row>
+    where
+        R: Read + 'a,
+    {
+        match self {
+            Self::Lines => Box::new(self.items(reader).map(|item| item.map(json_row))),
+            Self::Csv => {
+                let mut reader = csv::Reader::from_reader(reader);
+                let header = match reader.headers() {
+                    Ok(header) => header.clone(),
+                    Err(err) => return Box::new(once(Err(err.into()))),
+                };
+
+                Box::new(
+                    reader
+                        .into_records()
+                        .map(move |record| Ok(csv_row(&header, &record?))),
+                )
+            }
+        }
+    }
+
+    pub fn read<R, T>(self, reader: R) -> Result<Vec<T>, Error>
+    where
+        R: Read,
+        T: DeserializeOwned,
+    {
+        self.items(reader).collect()
+    }
+
+    pub fn write<'a, W, T, I>(self, writer: W, items: I, header: bool) -> Result<(), Error>
+    where
+        W: Write,
+        T: Serialize + 'a,
+        I: IntoIterator<Item = &'a T>,
+    {
+        match self {
+            Self::Lines => {
+                let mut writer = writer;
+
+                for item in items {
+                    serde_json::to_writer(&mut writer, item)?;
+                    writer.write_all(b"\n")?;
+                }
+
+                Ok(writer.flush()?)
+            }
+            Self::Csv => {
+                let mut writer = csv::WriterBuilder::new()
+                    .has_headers(header)
+                    .from_writer(writer);
+
+                for item in items {
+                    writer.serialize(item)?;
+                }
+
+                Ok(writer.flush()?)
+            }
+        }
+    }
+}
+
+pub(crate) fn header<R>(reader: R) -> Result<StringRecord, Error>
+where
+    R: Read,
+{
+    Ok(csv::Reader::from_reader(reader).headers()?.clone())
+}
+
+pub(crate) fn arrange<T>(item: &T, header: &StringRecord) -> Result<Option<StringRecord>, Error>
+where
+    T: Serialize,
+{
+    let mut writer = csv::Writer::from_writer(Vec::new());
+
+    writer.serialize(item)?;
+
+    let buffer = writer
+        .into_inner()
+        .map_err(|err| Error::Io(err.into_error()))?;
+    let mut reader = csv::Reader::from_reader(&buffer[..]);
+    let columns = reader.headers()?.clone();
+    let record = match reader.records().next() {
+        Some(record) => record?,
+        None => return Ok(None),
+    };
+
+    if columns.len() != header.len() {
+        return Ok(None);
+    }
+
+    let mut arranged = StringRecord::new();
+
+    for name in header {
+        match columns.iter().position(|column| column == name) {
+            Some(index) => arranged.push_field(&record[index]),
+            None => return Ok(None),
+        }
+    }
+
+    Ok(Some(arranged))
+}
+
+fn json_row(object: Map<String, Json>) -> Row {
+    let mut row = Row::new();
+
+    for (name, value) in object {
+        let value = match value {
+            Json::Null => continue,
+            Json::Bool(value) => Value::Bool(value),
+            Json::Number(number) => match (number.as_i64(), number.as_f64()) {
+                (Some(value), _) => Value::Integer(value),
+                (None, Some(value)) => Value::Float(value),
+                (None, None) => Value::Text(number.to_string()),
+            },
+            Json::String(value) => Value::Text(value),
+            value => Value::Text(value.to_string()),
+        };
+
+        row.insert(name, value);
+    }
+
+    row
+}
+
+// CSV cells carry no type, so they are read back as the narrowest value
+// that parses, the same way numbers and booleans were written out.
+fn csv_row(header: &StringRecord, record: &StringRecord) -> Row {
+    let mut row = Row::new();
+
+    for (name, cell) in header.iter().zip(record) {
+        let value = if cell.is_empty() {
+            continue;
+        } else if let Ok(value) = cell.parse() {
+            Value::Integer(value)
+        } else if let Ok(value) = cell.parse() {
+            Value::Float(value)
+        } else if let Ok(value) = cell.parse() {
+            Value::Bool(value)
+        } else {
+            Value::Text(cell.to_owned())
+        };
+
+        row.insert(name, value);
+    }
+
+    row
+}
+
+#[cfg(test)]
+mod tests {
+    use brace_data_store::{Row, Value};
+    use csv::StringRecord;
+    use serde::Serialize;
+
+    use super::{arrange, Format};
+
+    #[derive(Serialize)]
+    struct User {
+        name: &'static str,
+        age: i64,
+    }
+
+    #[test]
+    fn test_arrange() {
+        let user = User {
+            name: "alice",
+            age: 31,
+        };
+
+        assert_eq!(
+            arrange(&user, &StringRecord::from(vec!["age", "name"])).unwrap(),
+            Some(StringRecord::from(vec!["31", "alice"]))
+        );
+        assert_eq!(
+            arrange(&user, &StringRecord::from(vec!["name", "email"])).unwrap(),
+            None
+        );
+        assert_eq!(
+            arrange(&user, &StringRecord::from(vec!["name"])).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_rows() {
+        let row = vec![
+            ("name", Value::from("alice")),
+            ("age", Value::from(31)),
+            ("score", Value::from(1.5)),
+            ("admin", Value::from(true)),
+        ]
+        .into_iter()
+        .collect::<Row>();
+        let lines = "{\"name\":\"alice\",\"age\":31,\"score\":1.5,\"admin\":true,\"email\":null}\n";
+        let csv = "name,age,score,admin,email\nalice,31,1.5,true,\n";
+
+        assert_eq!(
+            Format::Lines
+                .rows(lines.as_bytes())
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+            vec![row.clone()]
+        );
+        assert_eq!(
+            Format::Csv
+                .rows(csv.as_bytes())
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+            vec![row]
+        );
+    }
+}
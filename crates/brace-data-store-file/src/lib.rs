@@ -0,0 +1,536 @@
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufWriter, Read, Seek, SeekFrom, Write};
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use futures::future::{ready, Ready};
+use futures::stream::iter;
+use once_cell::sync::Lazy;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use brace_data_store::query::{
+    count, delete, exists, filter, get, insert, select, update, upsert, with,
+};
+use brace_data_store::{
+    relate, resolve, Count, Delete, Error as StoreError, Exists, Expr, Fields, Filter, Get, Insert,
+    Keyed, Modify, Options, Predicate, Record, Records, Related, Row, Select, Store, Update,
+    Upsert, With,
+};
+
+pub use self::error::Error;
+pub use self::format::{Format, Items};
+
+pub mod error;
+pub mod format;
+
+static LOCKS: Lazy<Mutex<HashMap<PathBuf, Arc<Mutex<()>>>>> = Lazy::new(Default::default);
+static TEMP: AtomicUsize = AtomicUsize::new(0);
+
+pub struct FileStore<T> {
+    path: PathBuf,
+    format: Format,
+    lock: Arc<Mutex<()>>,
+    tables: HashMap<String, PathBuf>,
+    marker: PhantomData<T>,
+}
+
+impl<T> FileStore<T> {
+    pub fn new<P>(path: P, format: Format) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        let path = path.into();
+
+        Self {
+            lock: shared(&path),
+            path,
+            format,
+            tables: HashMap::new(),
+            marker: PhantomData,
+        }
+    }
+
+    pub fn lines<P>(path: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self::new(path, Format::Lines)
+    }
+
+    pub fn csv<P>(path: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self::new(path, Format::Csv)
+    }
+
+    // Relations are loaded from the files registered here, which are read as
+    // rows in the same format as the store.
+    pub fn with_table<N, P>(mut self, name: N, path: P) -> Self
+    where
+        N: Into<String>,
+        P: Into<PathBuf>,
+    {
+        self.tables.insert(name.into(), path.into());
+        self
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, ()>, StoreError> {
+        guard(&self.lock)
+    }
+
+    // Reads stop at the length the file had when it was opened, so appends
+    // made afterwards are not seen half written. Rewrites rename a new file
+    // into place and leave the opened one untouched.
+    fn open<'a>(&self) -> Result<Items<'a, T>, Error>
+    where
+        T: DeserializeOwned + 'a,
+    {
+        match File::open(&self.path) {
+            Ok(file) => {
+                let len = file.metadata()?.len();
+
+                Ok(self.format.items(file.take(len)))
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Box::new(std::iter::empty())),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn items<'a>(&self) -> Result<Items<'a, T>, StoreError>
+    where
+        T: DeserializeOwned + 'a,
+    {
+        let _lock = self.lock()?;
+
+        Ok(self.open()?)
+    }
+
+    fn read(&self) -> Result<Vec<T>, Error>
+    where
+        T: DeserializeOwned,
+    {
+        self.open()?.collect()
+    }
+
+    fn rewrite<'a, I>(&self, items: I) -> Result<(), Error>
+    where
+        T: Serialize + 'a,
+        I: IntoIterator<Item = &'a T>,
+    {
+        let mut temp = self.path.clone().into_os_string();
+
+        temp.push(format!(
+            ".{}.{}.tmp",
+            process::id(),
+            TEMP.fetch_add(1, Ordering::Relaxed)
+        ));
+
+        let temp = PathBuf::from(temp);
+        let result = self.replace(&temp, items);
+
+        if result.is_err() {
+            fs::remove_file(&temp).ok();
+        }
+
+        result
+    }
+
+    fn replace<'a, I>(&self, temp: &Path, items: I) -> Result<(), Error>
+    where
+        T: Serialize + 'a,
+        I: IntoIterator<Item = &'a T>,
+    {
+        let file = OpenOptions::new().write(true).create_new(true).open(temp)?;
+
+        self.format.write(BufWriter::new(&file), items, true)?;
+        file.sync_all()?;
+
+        Ok(fs::rename(temp, &self.path)?)
+    }
+
+    fn append(&self, item: &T) -> Result<(), Error>
+    where
+        T: DeserializeOwned + Serialize,
+    {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(&self.path)?;
+
+        if file.metadata()?.len() == 0 {
+            self.format.write(BufWriter::new(&file), Some(item), true)?;
+
+            return Ok(file.sync_all()?);
+        }
+
+        let mut last = [0];
+
+        file.seek(SeekFrom::End(-1))?;
+        file.read_exact(&mut last)?;
+
+        if last != *b"\n" {
+            file.write_all(b"\n")?;
+        }
+
+        match self.format {
+            Format::Lines => {
+                self.format
+                    .write(BufWriter::new(&file), Some(item), false)?;
+            }
+            Format::Csv => {
+                file.seek(SeekFrom::Start(0))?;
+
+                let header = format::header(&file)?;
+                let record = format::arrange(item, &header)?.ok_or(Error::Header)?;
+                let mut writer = csv::Writer::from_writer(BufWriter::new(&file));
+
+                writer.write_record(&record)?;
+                writer.flush()?;
+            }
+        }
+
+        Ok(file.sync_all()?)
+    }
+
+    fn rows(&self, table: &str, lookup: &Expr) -> Result<Vec<Row>, StoreError> {
+        let path = self
+            .tables
+            .get(table)
+            .ok_or_else(|| StoreError::not_found(format!("Unknown table `{}`", table)))?;
+        let lock = shared(path);
+        let _lock = guard(&lock)?;
+        let file = match File::open(path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(Error::from(err).into()),
+        };
+        let mut rows = Vec::new();
+
+        for row in self.format.rows(file) {
+            let row = row?;
+
+            if lookup.evaluate(&row) {
+                rows.push(row);
+            }
+        }
+
+        Ok(rows)
+    }
+
+    // Every item is read so that a bad line fails the query, but without an
+    // order only the requested window is kept in memory.
+    fn load<P>(&self, predicate: Option<P>, options: Options) -> Result<Vec<T>, StoreError>
+    where
+        T: DeserializeOwned + Fields,
+        P: Predicate<T>,
+    {
+        let ordered = !options.order().is_empty();
+        let offset = options.offset().unwrap_or(0);
+        let limit = options.limit().unwrap_or(usize::MAX);
+        let mut items = Vec::new();
+        let mut skipped = 0;
+
+        for item in self.items()? {
+            let item = item?;
+
+            if !matches(predicate.as_ref(), &item) {
+                continue;
+            }
+
+            if ordered {
+                items.push(item);
+            } else if skipped < offset {
+                skipped += 1;
+            } else if items.len() < limit {
+                items.push(item);
+            }
+        }
+
+        if ordered {
+            return Ok(options.apply(items));
+        }
+
+        Ok(items)
+    }
+}
+
+fn guard(lock: &Mutex<()>) -> Result<MutexGuard<'_, ()>, StoreError> {
+    lock.lock()
+        .map_err(|_| StoreError::message("File store lock poisoned"))
+}
+
+fn shared(path: &Path) -> Arc<Mutex<()>> {
+    let path = match (path.parent(), path.file_name()) {
+        (Some(parent), Some(name)) if parent.as_os_str().is_empty() => {
+            fs::canonicalize(".").map(|parent| parent.join(name))
+        }
+        (Some(parent), Some(name)) => fs::canonicalize(parent).map(|parent| parent.join(name)),
+        _ => Ok(path.to_owned()),
+    }
+    .unwrap_or_else(|_| path.to_owned());
+    let mut locks = LOCKS.lock().unwrap_or_else(PoisonError::into_inner);
+
+    locks.retain(|_, lock| Arc::strong_count(lock) > 1);
+    locks.entry(path).or_default().clone()
+}
+
+impl<T> Clone for FileStore<T> {
+    fn clone(&self) -> Self {
+        Self {
+            path: self.path.clone(),
+            format: self.format,
+            lock: self.lock.clone(),
+            tables: self.tables.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Store for FileStore<T> {
+    type Item = T;
+}
+
+impl<'a, T> Select<'a, T> for FileStore<T>
+where
+    T: DeserializeOwned + Fields + 'a,
+{
+    type Output = Ready<Result<Records<'a, T>, select::Error>>;
+
+    fn execute(&'a self, options: Options) -> Self::Output {
+        ready(self.load::<fn(&T) -> bool>(None, options).map(records))
+    }
+}
+
+impl<'a, T, P> Filter<'a, T, P> for FileStore<T>
+where
+    T: DeserializeOwned + Fields + 'a,
+    P: Predicate<T>,
+{
+    type Output = Ready<Result<Records<'a, T>, filter::Error>>;
+
+    fn execute(&'a self, predicate: P, options: Options) -> Self::Output {
+        ready(self.load(Some(predicate), options).map(records))
+    }
+}
+
+impl<'a, T> Get<'a, T> for FileStore<T>
+where
+    T: DeserializeOwned + Keyed,
+    T::Key: PartialEq,
+{
+    type Output = Ready<Result<Option<Record<T>>, get::Error>>;
+
+    fn execute(&'a self, key: T::Key) -> Self::Output {
+        ready(self.items().and_then(|items| {
+            for item in items {
+                let item = item?;
+
+                if item.key() == key {
+                    return Ok(Some(Record::new(item)));
+                }
+            }
+
+            Ok(None)
+        }))
+    }
+}
+
+impl<'a, T> Insert<'a, T> for FileStore<T>
+where
+    T: DeserializeOwned + Serialize + Keyed,
+    T::Key: PartialEq,
+{
+    type Output = Ready<Result<Record<T>, insert::Error>>;
+
+    fn execute(&'a self, item: T) -> Self::Output {
+        ready(self.lock().and_then(|_lock| {
+            let key = item.key();
+
+            for current in self.open()? {
+                if current?.key() == key {
+                    return Err(StoreError::conflict("Item already exists"));
+                }
+            }
+
+            self.append(&item)?;
+
+            Ok(Record::new(item))
+        }))
+    }
+}
+
+impl<'a, T> Upsert<'a, T> for FileStore<T>
+where
+    T: Clone + DeserializeOwned + Serialize + Keyed,
+    T::Key: PartialEq,
+{
+    type Output = Ready<Result<Record<T>, upsert::Error>>;
+
+    fn execute(&'a self, item: T) -> Self::Output {
+        ready(self.lock().and_then(|_lock| {
+            let mut items = self.read()?;
+            let key = item.key();
+
+            match items.iter_mut().find(|current| current.key() == key) {
+                Some(current) => *current = item.clone(),
+                None => items.push(item.clone()),
+            }
+
+            self.rewrite(&items)?;
+
+            Ok(Record::new(item))
+        }))
+    }
+}
+
+impl<'a, T, P, M> Update<'a, T, P, M> for FileStore<T>
+where
+    T: Clone + DeserializeOwned + Serialize + 'a,
+    P: Predicate<T>,
+    M: Modify<T>,
+{
+    type Output = Ready<Result<Records<'a, T>, update::Error>>;
+
+    fn execute(&'a self, predicate: P, modify: M) -> Self::Output {
+        ready(self.lock().and_then(|_lock| {
+            let mut items = self.read()?;
+            let mut updated = Vec::new();
+
+            for item in items.iter_mut() {
+                if predicate.test(item) {
+                    modify.modify(item);
+                    updated.push(item.clone());
+                }
+            }
+
+            if !updated.is_empty() {
+                self.rewrite(&items)?;
+            }
+
+            Ok(records(updated))
+        }))
+    }
+}
+
+impl<'a, T, P> Delete<'a, T, P> for FileStore<T>
+where
+    T: DeserializeOwned + Serialize,
+    P: Predicate<T>,
+{
+    type Output = Ready<Result<usize, delete::Error>>;
+
+    fn execute(&'a self, predicate: P) -> Self::Output {
+        ready(self.lock().and_then(|_lock| {
+            let mut items = self.read()?;
+            let len = items.len();
+
+            items.retain(|item| !predicate.test(item));
+
+            if items.len() < len {
+                self.rewrite(&items)?;
+            }
+
+            Ok(len - items.len())
+        }))
+    }
+}
+
+impl<'a, T, P> Count<'a, T, P> for FileStore<T>
+where
+    T: DeserializeOwned,
+    P: Predicate<T>,
+{
+    type Output = Ready<Result<usize, count::Error>>;
+
+    fn execute(&'a self, predicate: Option<P>) -> Self::Output {
+        ready(self.items().and_then(|items| {
+            let mut count = 0;
+
+            for item in items {
+                if matches(predicate.as_ref(), &item?) {
+                    count += 1;
+                }
+            }
+
+            Ok(count)
+        }))
+    }
+}
+
+impl<'a, T, P> Exists<'a, T, P> for FileStore<T>
+where
+    T: DeserializeOwned,
+    P: Predicate<T>,
+{
+    type Output = Ready<Result<bool, exists::Error>>;
+
+    fn execute(&'a self, predicate: Option<P>) -> Self::Output {
+        ready(self.items().and_then(|items| {
+            for item in items {
+                if matches(predicate.as_ref(), &item?) {
+                    return Ok(true);
+                }
+            }
+
+            Ok(false)
+        }))
+    }
+}
+
+impl<'a, T, P> With<'a, T, P> for FileStore<T>
+where
+    T: DeserializeOwned + Fields + Related + 'a,
+    P: Predicate<T>,
+{
+    type Output = Ready<Result<Records<'a, T>, with::Error>>;
+
+    fn execute(
+        &'a self,
+        relations: Vec<String>,
+        predicate: Option<P>,
+        options: Options,
+    ) -> Self::Output {
+        ready(resolve::<T>(&relations).and_then(|relations| {
+            let mut records = self
+                .load(predicate, options)?
+                .into_iter()
+                .map(Record::new)
+                .collect::<Vec<_>>();
+
+            relate(&mut records, &relations, &|table: &str, lookup: &Expr| {
+                self.rows(table, lookup)
+            })?;
+
+            Ok(Records::from_stream(iter(records)))
+        }))
+    }
+}
+
+fn matches<T, P>(predicate: Option<&P>, item: &T) -> bool
+where
+    P: Predicate<T>,
+{
+    predicate.map_or(true, |predicate| predicate.test(item))
+}
+
+fn records<'a, T>(items: Vec<T>) -> Records<'a, T>
+where
+    T: 'a,
+{
+    Records::from_stream(iter(items.into_iter().map(Record::new)))
+}
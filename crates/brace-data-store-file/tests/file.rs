@@ -0,0 +1,321 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+use std::thread;
+
+use futures::stream::StreamExt;
+use serde::{Deserialize, Serialize};
+
+use brace_data_store::{
+    field, Error, ErrorKind, Fields, Keyed, Options, Order, Related, Relation, Store, Value,
+};
+use brace_data_store_file::{FileStore, Format};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct User {
+    name: String,
+    age: i64,
+}
+
+impl User {
+    fn new(name: &str, age: i64) -> Self {
+        Self {
+            name: name.to_owned(),
+            age,
+        }
+    }
+}
+
+impl Keyed for User {
+    type Key = String;
+
+    fn key(&self) -> Self::Key {
+        self.name.clone()
+    }
+}
+
+impl Fields for User {
+    fn field(&self, name: &str) -> Option<Value> {
+        match name {
+            "name" => Some(Value::from(self.name.clone())),
+            "age" => Some(Value::from(self.age)),
+            _ => None,
+        }
+    }
+}
+
+impl Related for User {
+    fn relations() -> Vec<Relation> {
+        vec![Relation::has_many("posts", "posts", "author").with_local("name")]
+    }
+}
+
+fn path(name: &str) -> PathBuf {
+    let path = env::temp_dir().join(format!("brace-data-store-file-{}-{}", process::id(), name));
+
+    fs::remove_file(&path).ok();
+
+    path
+}
+
+async fn users(path: PathBuf, format: Format) -> Result<FileStore<User>, Error> {
+    let store = FileStore::new(path, format);
+
+    store.insert(User::new("alice", 31)).await?;
+    store.insert(User::new("bob", 27)).await?;
+    store.insert(User::new("carol", 45)).await?;
+
+    Ok(store)
+}
+
+fn names(users: Vec<User>) -> Vec<String> {
+    users.into_iter().map(|user| user.name).collect()
+}
+
+#[tokio::test]
+async fn test_file_lines() -> Result<(), Error> {
+    let path = path("users.jsonl");
+    let store = users(path.clone(), Format::Lines).await?;
+
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "{\"name\":\"alice\",\"age\":31}\n{\"name\":\"bob\",\"age\":27}\n{\"name\":\"carol\",\"age\":45}\n"
+    );
+
+    let options = Options::new().order_by(Order::desc("age")).with_limit(2);
+    let records = store.select_with(options).await?;
+    let users = records.map(|record| record.into_inner()).collect();
+
+    assert_eq!(names(users.await), vec!["carol", "alice"]);
+
+    let records = store.filter(field("age").gt(30)).await?;
+    let users = records.map(|record| record.into_inner()).collect();
+
+    assert_eq!(names(users.await), vec!["alice", "carol"]);
+    assert_eq!(store.count_where(field("age").lt(30)).await?, 1);
+
+    fs::remove_file(&path).ok();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_file_csv() -> Result<(), Error> {
+    let path = path("users.csv");
+    let store = users(path.clone(), Format::Csv).await?;
+
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "name,age\nalice,31\nbob,27\ncarol,45\n"
+    );
+
+    store
+        .update(field("name").eq("bob"), |user: &mut User| user.age += 1)
+        .await?;
+    store.upsert(User::new("dave", 52)).await?;
+
+    assert_eq!(store.delete(field("age").gt(40)).await?, 2);
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "name,age\nalice,31\nbob,28\n"
+    );
+    assert_eq!(
+        store.get("bob".to_owned()).await?.map(|record| record.age),
+        Some(28)
+    );
+
+    fs::remove_file(&path).ok();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_file_with() -> Result<(), Error> {
+    for (format, posts) in [
+        (
+            Format::Lines,
+            "{\"author\":\"alice\",\"title\":\"First\"}\n\
+             {\"author\":\"alice\",\"title\":\"Second\"}\n\
+             {\"author\":\"carol\",\"title\":\"Third\"}\n",
+        ),
+        (
+            Format::Csv,
+            "author,title\nalice,First\nalice,Second\ncarol,Third\n",
+        ),
+    ] {
+        let table = path(&format!("with-{:?}-posts", format));
+        let path = path(&format!("with-{:?}", format));
+
+        fs::write(&table, posts).unwrap();
+
+        let store = users(path.clone(), format)
+            .await?
+            .with_table("posts", &table);
+        let options = Options::new().order_by(Order::asc("name"));
+        let records = store
+            .with_where(vec!["posts"], field("age").gt(0), options)
+            .await?
+            .collect::<Vec<_>>()
+            .await;
+        let titles = records
+            .iter()
+            .map(|record| {
+                record
+                    .related("posts")
+                    .unwrap_or_default()
+                    .iter()
+                    .filter_map(|row| row.field("title"))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(records.len(), 3);
+        assert_eq!(titles[0], vec![Value::from("First"), Value::from("Second")]);
+        assert!(titles[1].is_empty());
+        assert_eq!(titles[2], vec![Value::from("Third")]);
+        assert!(store.with(vec!["missing"]).await.is_err());
+
+        fs::remove_file(&path).ok();
+        fs::remove_file(&table).ok();
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_file_missing_invalid() -> Result<(), Error> {
+    let path = path("invalid.jsonl");
+    let store = FileStore::<User>::lines(path.clone());
+
+    assert_eq!(store.count().await?, 0);
+    assert!(!store.exists().await?);
+
+    fs::write(&path, "{\"name\":\"alice\"}\n").unwrap();
+
+    let error = store.count().await.unwrap_err();
+
+    assert_eq!(error.kind(), ErrorKind::Serialization);
+
+    fs::remove_file(&path).ok();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_file_select_invalid() -> Result<(), Error> {
+    let path = path("corrupt.jsonl");
+    let store = FileStore::<User>::lines(path.clone());
+
+    fs::write(
+        &path,
+        "{\"name\":\"alice\",\"age\":31}\n{\"name\":\"bob\"\n{\"name\":\"carol\",\"age\":45}\n",
+    )
+    .unwrap();
+
+    let error = store.select().await.err().unwrap();
+
+    assert_eq!(error.kind(), ErrorKind::Serialization);
+
+    let options = Options::new().with_limit(1);
+    let error = store.select_with(options).await.err().unwrap();
+
+    assert_eq!(error.kind(), ErrorKind::Serialization);
+
+    let error = store.filter(field("age").gt(40)).await.err().unwrap();
+
+    assert_eq!(error.kind(), ErrorKind::Serialization);
+
+    fs::remove_file(&path).ok();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_file_insert_conflict() -> Result<(), Error> {
+    let path = path("conflict.jsonl");
+    let store = users(path.clone(), Format::Lines).await?;
+
+    let error = store.insert(User::new("bob", 60)).await.unwrap_err();
+
+    assert_eq!(error.kind(), ErrorKind::Conflict);
+    assert_eq!(store.count().await?, 3);
+
+    fs::remove_file(&path).ok();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_file_append_newline() -> Result<(), Error> {
+    let path = path("newline.jsonl");
+    let store = FileStore::<User>::lines(path.clone());
+
+    fs::write(&path, "{\"name\":\"alice\",\"age\":31}").unwrap();
+    store.insert(User::new("bob", 27)).await?;
+
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "{\"name\":\"alice\",\"age\":31}\n{\"name\":\"bob\",\"age\":27}\n"
+    );
+
+    fs::remove_file(&path).ok();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_file_csv_header() -> Result<(), Error> {
+    let path = path("header.csv");
+    let store = FileStore::<User>::csv(path.clone());
+
+    fs::write(&path, "age,name\n31,alice").unwrap();
+    store.insert(User::new("bob", 27)).await?;
+
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "age,name\n31,alice\n27,bob\n"
+    );
+
+    fs::write(&path, "name,age,email\nalice,31,alice@example.com\n").unwrap();
+
+    let error = store.insert(User::new("bob", 27)).await.unwrap_err();
+
+    assert_eq!(error.kind(), ErrorKind::Serialization);
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "name,age,email\nalice,31,alice@example.com\n"
+    );
+
+    fs::remove_file(&path).ok();
+
+    Ok(())
+}
+
+#[test]
+fn test_file_shared_lock() {
+    let path = path("shared.jsonl");
+    let threads = (0..8)
+        .map(|index| {
+            let store = FileStore::<User>::lines(path.clone());
+
+            thread::spawn(move || {
+                for age in 0..10 {
+                    let user = User::new(&format!("user-{}-{}", index, age), age);
+
+                    futures::executor::block_on(store.insert(user)).unwrap();
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for thread in threads {
+        thread.join().unwrap();
+    }
+
+    let store = FileStore::<User>::lines(path.clone());
+
+    assert_eq!(futures::executor::block_on(store.count()).unwrap(), 80);
+
+    fs::remove_file(&path).ok();
+}
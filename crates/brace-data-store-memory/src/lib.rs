@@ -0,0 +1,448 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::time::SystemTime;
+
+use futures::future::{ready, Ready};
+use futures::stream::iter;
+
+use brace_data_store::query::{
+    count, delete, exists, filter, get, insert, page, save, select, update, upsert, with,
+};
+use brace_data_store::{
+    paginate, relate, resolve, Count, Cursor, Delete, Error, Exists, Fields, Filter, Get, Insert,
+    Keyed, Meta, Modify, Options, Page, Paginate, Predicate, Record, Records, Related, Row, Save,
+    Select, Store, Update, Upsert, Value, With,
+};
+
+pub struct Memory<T>(Arc<RwLock<Inner<T>>>)
+where
+    T: Keyed;
+
+struct Inner<T>
+where
+    T: Keyed,
+{
+    records: Vec<Record<T>>,
+    index: HashMap<T::Key, usize>,
+    tables: HashMap<String, Vec<Row>>,
+    sequence: u64,
+}
+
+impl<T> Memory<T>
+where
+    T: Keyed,
+{
+    pub fn new() -> Self {
+        Self(Arc::new(RwLock::new(Inner {
+            records: Vec::new(),
+            index: HashMap::new(),
+            tables: HashMap::new(),
+            sequence: 0,
+        })))
+    }
+
+    pub fn len(&self) -> Result<usize, Error> {
+        Ok(self.read()?.records.len())
+    }
+
+    pub fn is_empty(&self) -> Result<bool, Error> {
+        Ok(self.len()? == 0)
+    }
+
+    // Relations are loaded from plain row tables kept next to the items, as
+    // there is no other store for the memory store to look them up in.
+    pub fn set_table<N>(&self, name: N, rows: Vec<Row>) -> Result<(), Error>
+    where
+        N: Into<String>,
+    {
+        self.write()?.tables.insert(name.into(), rows);
+
+        Ok(())
+    }
+
+    fn read(&self) -> Result<RwLockReadGuard<'_, Inner<T>>, Error> {
+        self.0
+            .read()
+            .map_err(|_| Error::message("Memory store lock poisoned"))
+    }
+
+    fn write(&self) -> Result<RwLockWriteGuard<'_, Inner<T>>, Error> {
+        self.0
+            .write()
+            .map_err(|_| Error::message("Memory store lock poisoned"))
+    }
+
+    fn filtered<P>(&self, predicate: Option<&P>) -> Result<Vec<Record<T>>, Error>
+    where
+        T: Clone,
+        P: Predicate<T>,
+    {
+        Ok(self
+            .read()?
+            .records
+            .iter()
+            .filter(|record| match predicate {
+                Some(predicate) => predicate.test(&**record),
+                None => true,
+            })
+            .cloned()
+            .collect())
+    }
+}
+
+impl<T> Inner<T>
+where
+    T: Keyed,
+    T::Key: Eq + Hash,
+{
+    fn position(&self, key: &T::Key) -> Option<usize> {
+        self.index.get(key).copied()
+    }
+
+    fn push(&mut self, record: Record<T>) {
+        self.index.insert(record.key(), self.records.len());
+        self.records.push(record);
+    }
+
+    fn reindex(&mut self) {
+        self.index = self
+            .records
+            .iter()
+            .enumerate()
+            .map(|(index, record)| (record.key(), index))
+            .collect();
+    }
+
+    fn create(&mut self, item: T) -> Record<T> {
+        let now = SystemTime::now();
+
+        self.sequence += 1;
+
+        Record::new(item).with_meta(
+            Meta::new()
+                .with_id(self.sequence.to_string())
+                .with_revision(1)
+                .with_created_at(now)
+                .with_updated_at(now),
+        )
+    }
+}
+
+fn touch<T>(record: &mut Record<T>) {
+    let revision = record.meta().revision().unwrap_or(0);
+
+    record.meta_mut().set_revision(revision + 1);
+    record.meta_mut().set_updated_at(SystemTime::now());
+}
+
+impl<T> Clone for Memory<T>
+where
+    T: Keyed,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> Default for Memory<T>
+where
+    T: Keyed,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Store for Memory<T>
+where
+    T: Keyed,
+{
+    type Item = T;
+}
+
+impl<'a, T> Select<'a, T> for Memory<T>
+where
+    T: Clone + Fields + Keyed + 'a,
+{
+    type Output = Ready<Result<Records<'a, T>, select::Error>>;
+
+    fn execute(&'a self, options: Options) -> Self::Output {
+        ready(
+            self.filtered::<fn(&T) -> bool>(None)
+                .map(|records| Records::from_stream(iter(options.apply(records)))),
+        )
+    }
+}
+
+impl<'a, T, P> Filter<'a, T, P> for Memory<T>
+where
+    T: Clone + Fields + Keyed + 'a,
+    P: Predicate<T>,
+{
+    type Output = Ready<Result<Records<'a, T>, filter::Error>>;
+
+    fn execute(&'a self, predicate: P, options: Options) -> Self::Output {
+        ready(
+            self.filtered(Some(&predicate))
+                .map(|records| Records::from_stream(iter(options.apply(records)))),
+        )
+    }
+}
+
+impl<'a, T> Get<'a, T> for Memory<T>
+where
+    T: Clone + Keyed,
+    T::Key: Eq + Hash,
+{
+    type Output = Ready<Result<Option<Record<T>>, get::Error>>;
+
+    fn execute(&'a self, key: T::Key) -> Self::Output {
+        ready(self.read().map(|inner| {
+            inner
+                .position(&key)
+                .map(|index| inner.records[index].clone())
+        }))
+    }
+}
+
+impl<'a, T> Insert<'a, T> for Memory<T>
+where
+    T: Clone + Keyed,
+    T::Key: Eq + Hash,
+{
+    type Output = Ready<Result<Record<T>, insert::Error>>;
+
+    fn execute(&'a self, item: T) -> Self::Output {
+        ready(self.write().and_then(|mut inner| {
+            if inner.position(&item.key()).is_some() {
+                return Err(Error::conflict("Item already exists"));
+            }
+
+            let record = inner.create(item);
+
+            inner.push(record.clone());
+
+            Ok(record)
+        }))
+    }
+}
+
+impl<'a, T> Upsert<'a, T> for Memory<T>
+where
+    T: Clone + Keyed,
+    T::Key: Eq + Hash,
+{
+    type Output = Ready<Result<Record<T>, upsert::Error>>;
+
+    fn execute(&'a self, item: T) -> Self::Output {
+        ready(
+            self.write()
+                .map(|mut inner| match inner.position(&item.key()) {
+                    Some(index) => {
+                        let record = &mut inner.records[index];
+
+                        **record = item;
+                        touch(record);
+
+                        record.clone()
+                    }
+                    None => {
+                        let record = inner.create(item);
+
+                        inner.push(record.clone());
+
+                        record
+                    }
+                }),
+        )
+    }
+}
+
+impl<'a, T, P, M> Update<'a, T, P, M> for Memory<T>
+where
+    T: Clone + Keyed + 'a,
+    T::Key: Eq + Hash,
+    P: Predicate<T>,
+    M: Modify<T>,
+{
+    type Output = Ready<Result<Records<'a, T>, update::Error>>;
+
+    fn execute(&'a self, predicate: P, modify: M) -> Self::Output {
+        ready(self.write().and_then(|mut inner| {
+            let mut updated = Vec::new();
+
+            for (index, record) in inner.records.iter().enumerate() {
+                if predicate.test(&**record) {
+                    let mut record = record.clone();
+
+                    modify.modify(&mut *record);
+                    touch(&mut record);
+                    updated.push((index, record));
+                }
+            }
+
+            let changed = updated
+                .iter()
+                .map(|(index, _)| *index)
+                .collect::<HashSet<_>>();
+            let mut keys = HashSet::new();
+
+            for (index, record) in &updated {
+                let key = record.key();
+
+                match inner.position(&key) {
+                    Some(other) if other != *index && !changed.contains(&other) => {
+                        return Err(Error::conflict("Item already exists"));
+                    }
+                    _ => {}
+                }
+
+                if !keys.insert(key) {
+                    return Err(Error::conflict("Item already exists"));
+                }
+            }
+
+            for (index, record) in &updated {
+                let key = inner.records[*index].key();
+
+                inner.index.remove(&key);
+                inner.records[*index] = record.clone();
+            }
+
+            for (index, record) in &updated {
+                inner.index.insert(record.key(), *index);
+            }
+
+            Ok(Records::from_stream(iter(
+                updated.into_iter().map(|(_, record)| record),
+            )))
+        }))
+    }
+}
+
+impl<'a, T> Save<'a, T> for Memory<T>
+where
+    T: Clone + Keyed,
+    T::Key: Eq + Hash,
+{
+    type Output = Ready<Result<Record<T>, save::Error<T>>>;
+
+    fn execute(&'a self, mut record: Record<T>) -> Self::Output {
+        let mut inner = match self.write() {
+            Ok(inner) => inner,
+            Err(error) => return ready(Err(error.into())),
+        };
+
+        let current = match inner.position(&record.key()) {
+            Some(index) => &mut inner.records[index],
+            None => return ready(Err(Error::not_found("Item does not exist").into())),
+        };
+
+        if current.meta().revision() != record.meta().revision() {
+            return ready(Err(save::Error::conflict(current.clone())));
+        }
+
+        *record.meta_mut() = current.meta().clone();
+        touch(&mut record);
+        *current = record.clone();
+
+        ready(Ok(record))
+    }
+}
+
+impl<'a, T, P> Delete<'a, T, P> for Memory<T>
+where
+    T: Keyed,
+    T::Key: Eq + Hash,
+    P: Predicate<T>,
+{
+    type Output = Ready<Result<usize, delete::Error>>;
+
+    fn execute(&'a self, predicate: P) -> Self::Output {
+        ready(self.write().map(|mut inner| {
+            let len = inner.records.len();
+
+            inner.records.retain(|record| !predicate.test(&**record));
+            inner.reindex();
+
+            len - inner.records.len()
+        }))
+    }
+}
+
+impl<'a, T, P> Count<'a, T, P> for Memory<T>
+where
+    T: Keyed,
+    P: Predicate<T>,
+{
+    type Output = Ready<Result<usize, count::Error>>;
+
+    fn execute(&'a self, predicate: Option<P>) -> Self::Output {
+        ready(self.read().map(|inner| {
+            match predicate {
+                Some(predicate) => inner
+                    .records
+                    .iter()
+                    .filter(|record| predicate.test(&**record))
+                    .count(),
+                None => inner.records.len(),
+            }
+        }))
+    }
+}
+
+impl<'a, T, P> Exists<'a, T, P> for Memory<T>
+where
+    T: Keyed,
+    P: Predicate<T>,
+{
+    type Output = Ready<Result<bool, exists::Error>>;
+
+    fn execute(&'a self, predicate: Option<P>) -> Self::Output {
+        ready(self.read().map(|inner| match predicate {
+            Some(predicate) => inner.records.iter().any(|record| predicate.test(&**record)),
+            None => !inner.records.is_empty(),
+        }))
+    }
+}
+
+impl<'a, T> Paginate<'a, T> for Memory<T>
+where
+    T: Clone + Fields + Keyed + 'a,
+    T::Key: Into<Value>,
+{
+    type Output = Ready<Result<Page<'a, T>, page::Error>>;
+
+    fn execute(&'a self, options: Options, cursor: Option<Cursor>) -> Self::Output {
+        ready(self.filtered::<fn(&T) -> bool>(None).map(|records| {
+            let (records, cursor) = paginate(records, &options, cursor.as_ref());
+
+            Page::new(Records::from_stream(iter(records)), cursor)
+        }))
+    }
+}
+
+impl<'a, T, P> With<'a, T, P> for Memory<T>
+where
+    T: Clone + Fields + Keyed + Related + 'a,
+    P: Predicate<T>,
+{
+    type Output = Ready<Result<Records<'a, T>, with::Error>>;
+
+    fn execute(
+        &'a self,
+        relations: Vec<String>,
+        predicate: Option<P>,
+        options: Options,
+    ) -> Self::Output {
+        ready(self.filtered(predicate.as_ref()).and_then(|records| {
+            let relations = resolve::<T>(&relations)?;
+            let mut records = options.apply(records);
+
+            relate(&mut records, &relations, &self.read()?.tables)?;
+
+            Ok(Records::from_stream(iter(records)))
+        }))
+    }
+}
@@ -0,0 +1,295 @@
+use std::thread;
+
+use futures::stream::StreamExt;
+
+use brace_data_store::{
+    field, Error, ErrorKind, Fields, Keyed, Options, Order, Related, Relation, Row, Store, Value,
+};
+use brace_data_store_memory::Memory;
+
+#[derive(Clone, Debug, PartialEq)]
+struct User {
+    name: String,
+    age: i64,
+}
+
+impl User {
+    fn new(name: &str, age: i64) -> Self {
+        Self {
+            name: name.to_owned(),
+            age,
+        }
+    }
+}
+
+impl Keyed for User {
+    type Key = String;
+
+    fn key(&self) -> Self::Key {
+        self.name.clone()
+    }
+}
+
+impl Fields for User {
+    fn field(&self, name: &str) -> Option<Value> {
+        match name {
+            "name" => Some(Value::from(self.name.clone())),
+            "age" => Some(Value::from(self.age)),
+            _ => None,
+        }
+    }
+}
+
+impl Related for User {
+    fn relations() -> Vec<Relation> {
+        vec![Relation::has_many("posts", "posts", "author").with_local("name")]
+    }
+}
+
+async fn users() -> Result<Memory<User>, Error> {
+    let memory = Memory::new();
+
+    memory.insert(User::new("alice", 31)).await?;
+    memory.insert(User::new("bob", 27)).await?;
+    memory.insert(User::new("carol", 45)).await?;
+
+    Ok(memory)
+}
+
+fn names(users: Vec<User>) -> Vec<String> {
+    users.into_iter().map(|user| user.name).collect()
+}
+
+#[tokio::test]
+async fn test_memory_insert_get() -> Result<(), Error> {
+    let memory = users().await?;
+    let record = memory.get("bob".to_owned()).await?.unwrap();
+
+    assert_eq!(*record, User::new("bob", 27));
+    assert_eq!(record.meta().id(), Some("2"));
+    assert_eq!(record.meta().revision(), Some(1));
+    assert!(record.meta().created_at().is_some());
+    assert!(memory.get("dave".to_owned()).await?.is_none());
+
+    let error = memory.insert(User::new("bob", 99)).await.unwrap_err();
+
+    assert_eq!(error.kind(), ErrorKind::Conflict);
+    assert_eq!(memory.len()?, 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_memory_select_order() -> Result<(), Error> {
+    let memory = users().await?;
+    let options = Options::new().order_by(Order::desc("age")).with_limit(2);
+    let records = memory.select_with(options).await?;
+    let users = records.map(|record| record.into_inner()).collect();
+
+    assert_eq!(names(users.await), vec!["carol", "alice"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_memory_filter() -> Result<(), Error> {
+    let memory = users().await?;
+    let records = memory.filter(field("age").gt(30)).await?;
+    let users = records.map(|record| record.into_inner()).collect();
+
+    assert_eq!(names(users.await), vec!["alice", "carol"]);
+    assert_eq!(memory.count_where(field("age").lt(30)).await?, 1);
+    assert!(!memory.exists_where(field("age").gt(50)).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_memory_update_delete() -> Result<(), Error> {
+    let memory = users().await?;
+    let records = memory
+        .update(field("name").eq("bob"), |user: &mut User| user.age += 1)
+        .await?;
+    let updated = records.collect::<Vec<_>>().await;
+
+    assert_eq!(updated.len(), 1);
+    assert_eq!(updated[0].age, 28);
+    assert_eq!(updated[0].meta().revision(), Some(2));
+
+    assert_eq!(memory.delete(field("age").gt(30)).await?, 2);
+    assert_eq!(memory.len()?, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_memory_update_key_conflict() -> Result<(), Error> {
+    let memory = users().await?;
+    let error = memory
+        .update(field("name").eq("bob"), |user: &mut User| {
+            user.name = "alice".to_owned()
+        })
+        .await
+        .err()
+        .unwrap();
+
+    assert_eq!(error.kind(), ErrorKind::Conflict);
+    assert_eq!(memory.get("bob".to_owned()).await?.unwrap().age, 27);
+
+    memory
+        .update(field("name").eq("bob"), |user: &mut User| {
+            user.name = "bert".to_owned()
+        })
+        .await?;
+
+    assert!(memory.get("bob".to_owned()).await?.is_none());
+    assert_eq!(memory.get("bert".to_owned()).await?.unwrap().age, 27);
+    assert_eq!(memory.delete(field("name").eq("alice")).await?, 1);
+    assert_eq!(memory.get("bert".to_owned()).await?.unwrap().age, 27);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_memory_save_conflict() -> Result<(), Error> {
+    let memory = users().await?;
+    let mut first = memory.get("alice".to_owned()).await?.unwrap();
+    let mut second = first.clone();
+
+    first.age = 32;
+
+    let saved = memory
+        .save(first)
+        .await
+        .map_err(|_| Error::message("save"))?;
+
+    assert_eq!(saved.meta().revision(), Some(2));
+
+    second.age = 33;
+
+    let error = memory.save(second).await.unwrap_err();
+
+    assert!(error.is_conflict());
+    assert_eq!(error.current().map(|record| record.age), Some(32));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_memory_with() -> Result<(), Error> {
+    let memory = users().await?;
+    let post = |author: &str, title: &str| {
+        vec![
+            ("author", Value::from(author)),
+            ("title", Value::from(title)),
+        ]
+        .into_iter()
+        .collect::<Row>()
+    };
+
+    memory.set_table(
+        "posts",
+        vec![
+            post("alice", "First"),
+            post("alice", "Second"),
+            post("carol", "Third"),
+        ],
+    )?;
+
+    let options = Options::new().order_by(Order::asc("name"));
+    let records = memory
+        .with_where(vec!["posts"], field("age").gt(0), options)
+        .await?
+        .collect::<Vec<_>>()
+        .await;
+    let titles = records
+        .iter()
+        .map(|record| {
+            record
+                .related("posts")
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|row| row.field("title"))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(records.len(), 3);
+    assert_eq!(titles[0], vec![Value::from("First"), Value::from("Second")]);
+    assert!(titles[1].is_empty());
+    assert_eq!(titles[2], vec![Value::from("Third")]);
+    assert!(memory.with(vec!["missing"]).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_memory_page() -> Result<(), Error> {
+    let memory = users().await?;
+    let options = Options::new().order_by(Order::asc("age")).with_limit(2);
+    let (records, cursor) = memory.page(options.clone(), None).await?.into_parts();
+    let users = records.map(|record| record.into_inner()).collect();
+
+    assert_eq!(names(users.await), vec!["bob", "alice"]);
+    assert!(cursor.is_some());
+
+    let (records, cursor) = memory.page(options, cursor).await?.into_parts();
+    let users = records.map(|record| record.into_inner()).collect();
+
+    assert_eq!(names(users.await), vec!["carol"]);
+    assert!(cursor.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_memory_page_ties() -> Result<(), Error> {
+    let memory = users().await?;
+
+    memory.insert(User::new("dave", 27)).await?;
+    memory.insert(User::new("erin", 27)).await?;
+
+    let options = Options::new().order_by(Order::asc("age")).with_limit(2);
+    let (records, cursor) = memory.page(options.clone(), None).await?.into_parts();
+    let users = records.map(|record| record.into_inner()).collect();
+
+    assert_eq!(names(users.await), vec!["bob", "dave"]);
+
+    let (records, _) = memory.page(options, cursor).await?.into_parts();
+    let users = records.map(|record| record.into_inner()).collect();
+
+    assert_eq!(names(users.await), vec!["erin", "alice"]);
+
+    let options = Options::new().with_limit(3);
+    let (_, cursor) = memory.page(options.clone(), None).await?.into_parts();
+    let (records, cursor) = memory.page(options, cursor).await?.into_parts();
+    let users = records.map(|record| record.into_inner()).collect();
+
+    assert_eq!(names(users.await), vec!["dave", "erin"]);
+    assert!(cursor.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_memory_shared() -> Result<(), Error> {
+    let memory = Memory::new();
+    let handles = (0..4)
+        .map(|i| {
+            let memory = memory.clone();
+
+            thread::spawn(move || {
+                futures::executor::block_on(memory.insert(User::new(&i.to_string(), i)))
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for handle in handles {
+        handle.join().unwrap()?;
+    }
+
+    assert_eq!(memory.len()?, 4);
+    assert_eq!(memory.count().await?, 4);
+
+    Ok(())
+}
@@ -1,5 +1,7 @@
 use std::fmt::{self, Display};
 
+use brace_data_store::error::{Error as StoreError, ErrorKind};
+
 #[derive(Debug)]
 pub enum Error {
     TimedOut,
@@ -31,3 +33,29 @@ impl From<bb8::RunError<tokio_postgres::Error>> for Error {
         }
     }
 }
+
+impl From<Error> for StoreError {
+    fn from(error: Error) -> Self {
+        let kind = match &error {
+            Error::TimedOut => ErrorKind::Timeout,
+            Error::User(err) => kind(err),
+        };
+
+        StoreError::from_source(kind, error)
+    }
+}
+
+fn kind(error: &tokio_postgres::Error) -> ErrorKind {
+    if error.is_closed() {
+        return ErrorKind::Connection;
+    }
+
+    match error.code().map(|code| code.code()) {
+        Some("40001") | Some("40P01") => ErrorKind::Conflict,
+        Some("57014") => ErrorKind::Timeout,
+        Some(code) if code.starts_with("23") => ErrorKind::ConstraintViolation,
+        Some(code) if code.starts_with("08") => ErrorKind::Connection,
+        Some(code) if code.starts_with("22") => ErrorKind::Serialization,
+        _ => ErrorKind::Backend,
+    }
+}
@@ -15,9 +15,17 @@ pub use rustls::ClientConfig as TlsConfig;
 
 pub use self::connection::PostgresConnection;
 pub use self::error::Error;
+pub use self::store::PostgresStore;
+pub use self::table::{Condition, Table};
+pub use self::transaction::PostgresTransaction;
 
 pub mod connection;
 pub mod error;
+pub mod sql;
+pub mod store;
+pub mod table;
+pub mod transaction;
+pub mod value;
 
 #[cfg(feature = "snakeoil")]
 const SNAKEOIL_CERT: &[u8] = include_bytes!("../fixtures/server.crt");
@@ -54,6 +62,10 @@ impl Postgres {
     pub fn connect(&self) -> FutureConnection<PostgresConnection> {
         self.0.connect()
     }
+
+    pub fn store<T>(&self) -> PostgresStore<T> {
+        PostgresStore::new(self.clone())
+    }
 }
 
 impl Default for Postgres {
@@ -0,0 +1,565 @@
+use brace_data_store::query::aggregate::{Aggregation, Function};
+use brace_data_store::query::expr::Expr;
+use brace_data_store::query::options::{Direction, Nulls, Options, Order};
+use brace_data_store::query::page::Cursor;
+use brace_data_store::query::project::Projection;
+use brace_data_store::transaction::Isolation;
+use brace_data_store::Value;
+
+pub fn ident(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+pub fn order(order: &Order) -> String {
+    let direction = match order.direction() {
+        Direction::Ascending => "ASC",
+        Direction::Descending => "DESC",
+    };
+
+    let nulls = match order.nulls() {
+        Nulls::First => "NULLS FIRST",
+        Nulls::Last => "NULLS LAST",
+    };
+
+    format!("{} {} {}", ident(order.field()), direction, nulls)
+}
+
+pub fn options(options: &Options) -> String {
+    let mut sql = String::new();
+
+    if !options.order().is_empty() {
+        let order = options.order().iter().map(order).collect::<Vec<_>>();
+
+        sql.push_str(" ORDER BY ");
+        sql.push_str(&order.join(", "));
+    }
+
+    if let Some(limit) = options.limit() {
+        sql.push_str(&format!(" LIMIT {}", limit));
+    }
+
+    if let Some(offset) = options.offset() {
+        sql.push_str(&format!(" OFFSET {}", offset));
+    }
+
+    sql
+}
+
+pub fn after(options: &Options, key: &str, cursor: &Cursor, start: usize) -> String {
+    let mut param = start;
+    let mut equal = Vec::new();
+    let mut branches = Vec::new();
+    let key = Order::asc(key).nulls_first();
+
+    for (order, value) in options
+        .order()
+        .iter()
+        .chain(Some(&key))
+        .zip(cursor.values())
+    {
+        let column = ident(order.field());
+
+        let (greater, same) = match value {
+            Some(_) => {
+                let operator = match order.direction() {
+                    Direction::Ascending => ">",
+                    Direction::Descending => "<",
+                };
+
+                let greater = match order.nulls() {
+                    Nulls::First => format!("{} {} ${}", column, operator, param),
+                    Nulls::Last => {
+                        format!("({} {} ${} OR {} IS NULL)", column, operator, param, column)
+                    }
+                };
+
+                let same = format!("{} = ${}", column, param);
+
+                param += 1;
+
+                (greater, same)
+            }
+            None => {
+                let greater = match order.nulls() {
+                    Nulls::First => format!("{} IS NOT NULL", column),
+                    Nulls::Last => "FALSE".to_owned(),
+                };
+
+                (greater, format!("{} IS NULL", column))
+            }
+        };
+
+        let mut branch = equal.clone();
+
+        branch.push(greater);
+        branches.push(format!("({})", branch.join(" AND ")));
+        equal.push(same);
+    }
+
+    if branches.is_empty() {
+        return "TRUE".to_owned();
+    }
+
+    format!("({})", branches.join(" OR "))
+}
+
+pub fn condition(expr: &Expr, start: usize) -> (String, Vec<Value>) {
+    let mut params = Vec::new();
+    let sql = compile(expr, start, &mut params);
+
+    (sql, params)
+}
+
+fn compile(expr: &Expr, start: usize, params: &mut Vec<Value>) -> String {
+    let mut param = |value: &Value| {
+        params.push(value.clone());
+        format!("${}", start + params.len() - 1)
+    };
+
+    match expr {
+        Expr::Eq(field, value) => format!("{} = {}", ident(field), param(value)),
+        Expr::Ne(field, value) => format!("{} <> {}", ident(field), param(value)),
+        Expr::Lt(field, value) => format!("{} < {}", ident(field), param(value)),
+        Expr::Le(field, value) => format!("{} <= {}", ident(field), param(value)),
+        Expr::Gt(field, value) => format!("{} > {}", ident(field), param(value)),
+        Expr::Ge(field, value) => format!("{} >= {}", ident(field), param(value)),
+        Expr::In(_, values) if values.is_empty() => "FALSE".to_owned(),
+        Expr::In(field, values) => {
+            let values = values.iter().map(param).collect::<Vec<_>>();
+
+            format!("{} IN ({})", ident(field), values.join(", "))
+        }
+        Expr::Like(field, pattern) => {
+            let pattern = param(&Value::Text(pattern.clone()));
+
+            format!("{} LIKE {}", ident(field), pattern)
+        }
+        Expr::IsNull(field) => format!("{} IS NULL", ident(field)),
+        Expr::And(exprs) => junction(exprs, " AND ", "TRUE", start, params),
+        Expr::Or(exprs) => junction(exprs, " OR ", "FALSE", start, params),
+        Expr::Not(expr) => format!("NOT ({})", compile(expr, start, params)),
+    }
+}
+
+fn junction(
+    exprs: &[Expr],
+    separator: &str,
+    empty: &str,
+    start: usize,
+    params: &mut Vec<Value>,
+) -> String {
+    if exprs.is_empty() {
+        return empty.to_owned();
+    }
+
+    let exprs = exprs
+        .iter()
+        .map(|expr| format!("({})", compile(expr, start, params)))
+        .collect::<Vec<_>>();
+
+    exprs.join(separator)
+}
+
+pub fn columns(projection: &Projection) -> String {
+    if projection.fields().is_empty() {
+        return "*".to_owned();
+    }
+
+    list(projection.fields())
+}
+
+pub fn select(table: &str, columns: &str, condition: Option<&str>) -> String {
+    format!(
+        "SELECT {} FROM {}{}",
+        columns,
+        ident(table),
+        filter(condition)
+    )
+}
+
+pub fn insert<T>(table: &str, columns: &[T]) -> String
+where
+    T: AsRef<str>,
+{
+    format!(
+        "INSERT INTO {} ({}) VALUES ({})",
+        ident(table),
+        list(columns),
+        params(columns.len(), 1)
+    )
+}
+
+pub fn upsert<T>(table: &str, columns: &[T], key: &str) -> String
+where
+    T: AsRef<str>,
+{
+    let updates = columns
+        .iter()
+        .map(|column| format!("{0} = EXCLUDED.{0}", ident(column.as_ref())))
+        .collect::<Vec<_>>();
+
+    format!(
+        "{} ON CONFLICT ({}) DO UPDATE SET {}",
+        insert(table, columns),
+        ident(key),
+        updates.join(", ")
+    )
+}
+
+pub fn update<T>(table: &str, columns: &[T], key: &str) -> String
+where
+    T: AsRef<str>,
+{
+    let columns = columns
+        .iter()
+        .enumerate()
+        .map(|(i, column)| format!("{} = ${}", ident(column.as_ref()), i + 1))
+        .collect::<Vec<_>>();
+
+    format!(
+        "UPDATE {} SET {} WHERE {} = ${}",
+        ident(table),
+        columns.join(", "),
+        ident(key),
+        columns.len() + 1
+    )
+}
+
+pub fn delete(table: &str, condition: Option<&str>) -> String {
+    format!("DELETE FROM {}{}", ident(table), filter(condition))
+}
+
+pub fn aggregate(
+    table: &str,
+    aggregation: &Aggregation,
+    condition: Option<&str>,
+    options: &Options,
+) -> String {
+    let groups = aggregation
+        .groups()
+        .iter()
+        .map(|field| ident(field))
+        .collect::<Vec<_>>();
+
+    let columns = groups
+        .iter()
+        .cloned()
+        .chain(aggregation.functions().iter().map(|(name, function)| {
+            let function = match function {
+                Function::Count => "count(*)".to_owned(),
+                Function::Sum(field) => format!("sum({})", ident(field)),
+                Function::Avg(field) => format!("avg({})", ident(field)),
+                Function::Min(field) => format!("min({})", ident(field)),
+                Function::Max(field) => format!("max({})", ident(field)),
+            };
+
+            format!("{} AS {}", function, ident(name))
+        }))
+        .collect::<Vec<_>>();
+
+    let mut sql = format!(
+        "SELECT {} FROM {}{}",
+        columns.join(", "),
+        ident(table),
+        filter(condition)
+    );
+
+    if !groups.is_empty() {
+        sql.push_str(" GROUP BY ");
+        sql.push_str(&groups.join(", "));
+    }
+
+    sql.push_str(&self::options(options));
+    sql
+}
+
+pub fn begin(isolation: Isolation) -> String {
+    let level = match isolation {
+        Isolation::ReadUncommitted => "READ UNCOMMITTED",
+        Isolation::ReadCommitted => "READ COMMITTED",
+        Isolation::RepeatableRead => "REPEATABLE READ",
+        Isolation::Serializable => "SERIALIZABLE",
+    };
+
+    format!("BEGIN ISOLATION LEVEL {}", level)
+}
+
+pub fn savepoint(name: &str) -> String {
+    format!("SAVEPOINT {}", ident(name))
+}
+
+pub fn rollback_to(name: &str) -> String {
+    format!("ROLLBACK TO SAVEPOINT {}", ident(name))
+}
+
+pub fn release(name: &str) -> String {
+    format!("RELEASE SAVEPOINT {}", ident(name))
+}
+
+pub fn count(table: &str, condition: Option<&str>) -> String {
+    format!("SELECT count(*) FROM {}{}", ident(table), filter(condition))
+}
+
+pub fn exists(table: &str, condition: Option<&str>) -> String {
+    format!(
+        "SELECT EXISTS (SELECT 1 FROM {}{})",
+        ident(table),
+        filter(condition)
+    )
+}
+
+fn list<T>(columns: &[T]) -> String
+where
+    T: AsRef<str>,
+{
+    columns
+        .iter()
+        .map(|column| ident(column.as_ref()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn params(len: usize, start: usize) -> String {
+    (start..start + len)
+        .map(|param| format!("${}", param))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn filter(condition: Option<&str>) -> String {
+    match condition {
+        Some(condition) => format!(" WHERE {}", condition),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use brace_data_store::query::aggregate::Aggregation;
+    use brace_data_store::query::expr::{field, Expr};
+    use brace_data_store::query::options::{Options, Order};
+    use brace_data_store::query::page::Cursor;
+    use brace_data_store::query::project::Projection;
+    use brace_data_store::transaction::Isolation;
+    use brace_data_store::Value;
+
+    use super::{
+        after, aggregate, begin, columns, condition, count, delete, exists, ident, insert, options,
+        release, rollback_to, savepoint, select, update, upsert,
+    };
+
+    #[test]
+    fn test_sql_ident() {
+        assert_eq!(ident("title"), "\"title\"");
+        assert_eq!(ident("ti\"tle"), "\"ti\"\"tle\"");
+    }
+
+    #[test]
+    fn test_sql_options() {
+        assert_eq!(options(&Options::new()), "");
+        assert_eq!(
+            options(
+                &Options::new()
+                    .order_by(Order::desc("created"))
+                    .order_by(Order::asc("title").nulls_first())
+                    .with_limit(10)
+                    .with_offset(20)
+            ),
+            " ORDER BY \"created\" DESC NULLS FIRST, \"title\" ASC NULLS FIRST LIMIT 10 OFFSET 20"
+        );
+        assert_eq!(options(&Options::new().with_limit(5)), " LIMIT 5");
+    }
+
+    #[test]
+    fn test_sql_after() {
+        let options = Options::new()
+            .order_by(Order::desc("created"))
+            .order_by(Order::asc("id").nulls_first());
+
+        assert_eq!(
+            after(
+                &options,
+                "key",
+                &Cursor::new(vec![
+                    Some(Value::Integer(5)),
+                    Some(Value::Integer(9)),
+                    Some(Value::Integer(2))
+                ]),
+                1
+            ),
+            "((\"created\" < $1) OR (\"created\" = $1 AND \"id\" > $2) \
+             OR (\"created\" = $1 AND \"id\" = $2 AND \"key\" > $3))"
+        );
+        assert_eq!(
+            after(
+                &options,
+                "key",
+                &Cursor::new(vec![None, Some(Value::Integer(9))]),
+                3
+            ),
+            "((\"created\" IS NOT NULL) OR (\"created\" IS NULL AND \"id\" > $3))"
+        );
+
+        let options = Options::new().order_by(Order::asc("title"));
+
+        assert_eq!(
+            after(
+                &options,
+                "key",
+                &Cursor::new(vec![Some(Value::from("a"))]),
+                1
+            ),
+            "(((\"title\" > $1 OR \"title\" IS NULL)))"
+        );
+        assert_eq!(
+            after(
+                &Options::new(),
+                "key",
+                &Cursor::new(vec![Some(Value::Integer(2))]),
+                1
+            ),
+            "((\"key\" > $1))"
+        );
+        assert_eq!(
+            after(&Options::new(), "key", &Cursor::new(Vec::new()), 1),
+            "TRUE"
+        );
+    }
+
+    #[test]
+    fn test_sql_select_insert_delete() {
+        assert_eq!(
+            select("books", "\"id\", \"title\"", Some("\"id\" = $1")),
+            "SELECT \"id\", \"title\" FROM \"books\" WHERE \"id\" = $1"
+        );
+        assert_eq!(
+            insert("books", &["id", "title"]),
+            "INSERT INTO \"books\" (\"id\", \"title\") VALUES ($1, $2)"
+        );
+        assert_eq!(delete("books", None), "DELETE FROM \"books\"");
+    }
+
+    #[test]
+    fn test_sql_upsert_update() {
+        assert_eq!(
+            upsert("books", &["id", "title"], "id"),
+            "INSERT INTO \"books\" (\"id\", \"title\") VALUES ($1, $2) ON CONFLICT (\"id\") \
+             DO UPDATE SET \"id\" = EXCLUDED.\"id\", \"title\" = EXCLUDED.\"title\""
+        );
+        assert_eq!(
+            update("books", &["id", "title"], "id"),
+            "UPDATE \"books\" SET \"id\" = $1, \"title\" = $2 WHERE \"id\" = $3"
+        );
+    }
+
+    #[test]
+    fn test_sql_count() {
+        assert_eq!(count("books", None), "SELECT count(*) FROM \"books\"");
+        assert_eq!(
+            count("books", Some("\"year\" > $1")),
+            "SELECT count(*) FROM \"books\" WHERE \"year\" > $1"
+        );
+    }
+
+    #[test]
+    fn test_sql_exists() {
+        assert_eq!(
+            exists("books", None),
+            "SELECT EXISTS (SELECT 1 FROM \"books\")"
+        );
+        assert_eq!(
+            exists("books", Some("\"year\" > $1")),
+            "SELECT EXISTS (SELECT 1 FROM \"books\" WHERE \"year\" > $1)"
+        );
+    }
+
+    #[test]
+    fn test_sql_condition() {
+        let expr = field("year")
+            .ge(1900)
+            .and(field("title").like("F%").or(field("id").is_in(vec![1, 2])))
+            .and(!field("author").is_null());
+
+        assert_eq!(
+            condition(&expr, 1),
+            (
+                "(\"year\" >= $1) AND ((\"title\" LIKE $2) OR (\"id\" IN ($3, $4))) AND (NOT (\"author\" IS NULL))".to_owned(),
+                vec![
+                    Value::Integer(1900),
+                    Value::from("F%"),
+                    Value::Integer(1),
+                    Value::Integer(2)
+                ]
+            )
+        );
+        assert_eq!(
+            condition(&field("id").ne(3), 4),
+            ("\"id\" <> $4".to_owned(), vec![Value::Integer(3)])
+        );
+        assert_eq!(
+            condition(&Expr::Or(Vec::new()), 1),
+            ("FALSE".to_owned(), Vec::new())
+        );
+        assert_eq!(
+            condition(&field("id").is_in(Vec::<i64>::new()), 1),
+            ("FALSE".to_owned(), Vec::new())
+        );
+    }
+
+    #[test]
+    fn test_sql_columns() {
+        assert_eq!(columns(&Projection::default()), "*");
+        assert_eq!(
+            columns(&Projection::new(vec!["id", "title"])),
+            "\"id\", \"title\""
+        );
+    }
+
+    #[test]
+    fn test_sql_aggregate() {
+        let aggregation = Aggregation::new()
+            .group_by("region")
+            .count("count")
+            .sum("units", "units")
+            .avg("price", "price");
+
+        assert_eq!(
+            aggregate(
+                "sales",
+                &aggregation,
+                Some("\"year\" = $1"),
+                &Options::new()
+            ),
+            "SELECT \"region\", count(*) AS \"count\", sum(\"units\") AS \"units\", avg(\"price\") AS \"price\" FROM \"sales\" WHERE \"year\" = $1 GROUP BY \"region\""
+        );
+        assert_eq!(
+            aggregate(
+                "sales",
+                &aggregation,
+                None,
+                &Options::new().order_by(Order::desc("units")).with_limit(1)
+            ),
+            "SELECT \"region\", count(*) AS \"count\", sum(\"units\") AS \"units\", avg(\"price\") AS \"price\" FROM \"sales\" GROUP BY \"region\" ORDER BY \"units\" DESC NULLS FIRST LIMIT 1"
+        );
+        assert_eq!(
+            aggregate(
+                "sales",
+                &Aggregation::new().max("max", "price"),
+                None,
+                &Options::new()
+            ),
+            "SELECT max(\"price\") AS \"max\" FROM \"sales\""
+        );
+    }
+
+    #[test]
+    fn test_sql_savepoint() {
+        assert_eq!(
+            begin(Isolation::RepeatableRead),
+            "BEGIN ISOLATION LEVEL REPEATABLE READ"
+        );
+        assert_eq!(savepoint("one"), "SAVEPOINT \"one\"");
+        assert_eq!(rollback_to("one"), "ROLLBACK TO SAVEPOINT \"one\"");
+        assert_eq!(release("one"), "RELEASE SAVEPOINT \"one\"");
+    }
+}
@@ -0,0 +1,671 @@
+use std::future::Future;
+use std::marker::PhantomData;
+
+use futures::pin_mut;
+use futures::stream::{iter, Stream, StreamExt};
+use tokio_postgres::Client;
+
+use brace_util_future::result::FutureResult;
+
+use brace_data_store::query::{
+    aggregate, batch, count, delete, exists, filter, get, insert, page, project, select, update,
+    upsert, with,
+};
+use brace_data_store::{
+    resolve, Aggregate, Aggregation, Batch, Count, Cursor, Delete, DeleteMany, Error as StoreError,
+    Exists, Expr, Filter, Get, Insert, InsertMany, Isolation, Keyed, Modify, Options, Order, Page,
+    Paginate, Predicate, Project, Projection, Record, Records, Related, Relation, Row, Select,
+    Store, Transaction, Update, Upsert, Value, With,
+};
+
+use crate::error::Error;
+use crate::sql;
+use crate::table::{Condition, Table};
+use crate::transaction::PostgresTransaction;
+use crate::value::{params, row, SqlValue};
+use crate::Postgres;
+
+// Rows carry no revision metadata, so the revision-checked `Save` is left to
+// stores that track revisions.
+pub struct PostgresStore<T> {
+    postgres: Postgres,
+    marker: PhantomData<T>,
+}
+
+impl<T> PostgresStore<T> {
+    pub fn new(postgres: Postgres) -> Self {
+        Self {
+            postgres,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn postgres(&self) -> &Postgres {
+        &self.postgres
+    }
+}
+
+impl<T> Clone for PostgresStore<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.postgres.clone())
+    }
+}
+
+impl<T> Store for PostgresStore<T> {
+    type Item = T;
+}
+
+impl<'a, T> Select<'a, T> for PostgresStore<T>
+where
+    T: Table + 'a,
+{
+    type Output = FutureResult<'a, Records<'a, T>, select::Error>;
+
+    fn execute(&'a self, options: Options) -> Self::Output {
+        let sql = sql::select(T::NAME, &columns::<T>(), None) + &sql::options(&options);
+
+        FutureResult::from_future(async move {
+            let conn = self.postgres.connect().await?;
+            let records = records::<T>(&conn, &sql, Vec::new()).await?;
+
+            Ok(Records::from_stream(iter(records)))
+        })
+    }
+}
+
+impl<'a, T, P> Filter<'a, T, P> for PostgresStore<T>
+where
+    T: Table + 'a,
+    P: Predicate<T> + Condition,
+{
+    type Output = FutureResult<'a, Records<'a, T>, filter::Error>;
+
+    fn execute(&'a self, predicate: P, options: Options) -> Self::Output {
+        let (condition, params) = condition(&predicate, 1);
+        let sql =
+            sql::select(T::NAME, &columns::<T>(), condition.as_deref()) + &sql::options(&options);
+
+        FutureResult::from_future(async move {
+            let conn = self.postgres.connect().await?;
+            let records = records::<T>(&conn, &sql, params).await?;
+
+            Ok(Records::from_stream(iter(records)))
+        })
+    }
+}
+
+impl<'a, T> Get<'a, T> for PostgresStore<T>
+where
+    T: Table + Keyed,
+    T::Key: Into<Value>,
+{
+    type Output = FutureResult<'a, Option<Record<T>>, get::Error>;
+
+    fn execute(&'a self, key: T::Key) -> Self::Output {
+        let condition = format!("{} = $1", sql::ident(T::KEY));
+        let sql = sql::select(T::NAME, &columns::<T>(), Some(&condition)) + " LIMIT 1";
+        let params = vec![key.into()];
+
+        FutureResult::from_future(async move {
+            let conn = self.postgres.connect().await?;
+            let records = records::<T>(&conn, &sql, params).await?;
+
+            Ok(records.into_iter().next())
+        })
+    }
+}
+
+impl<'a, T> Insert<'a, T> for PostgresStore<T>
+where
+    T: Table + 'a,
+{
+    type Output = FutureResult<'a, Record<T>, insert::Error>;
+
+    fn execute(&'a self, item: T) -> Self::Output {
+        let sql = sql::insert(T::NAME, T::COLUMNS);
+
+        FutureResult::from_future(async move {
+            let conn = self.postgres.connect().await?;
+
+            run(&conn, &sql, values(&item)).await?;
+
+            Ok(Record::new(item))
+        })
+    }
+}
+
+impl<'a, T> Upsert<'a, T> for PostgresStore<T>
+where
+    T: Table + Keyed + 'a,
+{
+    type Output = FutureResult<'a, Record<T>, upsert::Error>;
+
+    fn execute(&'a self, item: T) -> Self::Output {
+        let sql = sql::upsert(T::NAME, T::COLUMNS, T::KEY);
+
+        FutureResult::from_future(async move {
+            let conn = self.postgres.connect().await?;
+
+            run(&conn, &sql, values(&item)).await?;
+
+            Ok(Record::new(item))
+        })
+    }
+}
+
+impl<'a, T, P, M> Update<'a, T, P, M> for PostgresStore<T>
+where
+    T: Table + Keyed + 'a,
+    T::Key: Into<Value>,
+    P: Predicate<T> + Condition,
+    M: Modify<T> + 'a,
+{
+    type Output = FutureResult<'a, Records<'a, T>, update::Error>;
+
+    fn execute(&'a self, predicate: P, modify: M) -> Self::Output {
+        let (condition, params) = condition(&predicate, 1);
+        let sql = sql::select(T::NAME, &columns::<T>(), condition.as_deref());
+
+        FutureResult::from_future(async move {
+            let tx =
+                PostgresTransaction::<T>::begin(&self.postgres, Isolation::ReadCommitted).await?;
+            let records = apply(tx.client(), &sql, params, &modify).await?;
+
+            tx.commit().await?;
+
+            Ok(Records::from_stream(iter(records)))
+        })
+    }
+}
+
+impl<'a, T, S> InsertMany<'a, T, S> for PostgresStore<T>
+where
+    T: Table + 'a,
+    S: Stream<Item = T> + 'a,
+{
+    type Output = FutureResult<'a, Batch<T>, batch::Error>;
+
+    fn execute(&'a self, items: S) -> Self::Output {
+        let sql = sql::insert(T::NAME, T::COLUMNS);
+
+        FutureResult::from_future(async move {
+            let conn = self.postgres.connect().await?;
+            let mut batch = Batch::new();
+
+            pin_mut!(items);
+
+            while let Some(item) = items.next().await {
+                let result = run(&conn, &sql, values(&item)).await;
+
+                batch.push(result.map(|_| Record::new(item)));
+            }
+
+            Ok(batch)
+        })
+    }
+}
+
+impl<'a, T, P> Delete<'a, T, P> for PostgresStore<T>
+where
+    T: Table,
+    P: Predicate<T> + Condition,
+{
+    type Output = FutureResult<'a, usize, delete::Error>;
+
+    fn execute(&'a self, predicate: P) -> Self::Output {
+        let (condition, params) = condition(&predicate, 1);
+        let sql = sql::delete(T::NAME, condition.as_deref());
+
+        FutureResult::from_future(async move {
+            let conn = self.postgres.connect().await?;
+
+            run(&conn, &sql, bind(params)).await
+        })
+    }
+}
+
+impl<'a, T, S> DeleteMany<'a, T, S> for PostgresStore<T>
+where
+    T: Table + Keyed + 'a,
+    T::Key: Into<Value>,
+    S: Stream<Item = T::Key> + 'a,
+{
+    type Output = FutureResult<'a, Batch<T>, batch::Error>;
+
+    fn execute(&'a self, keys: S) -> Self::Output {
+        FutureResult::from_future(async move {
+            let conn = self.postgres.connect().await?;
+            let mut batch = Batch::new();
+
+            pin_mut!(keys);
+
+            while let Some(key) = keys.next().await {
+                batch.push(remove::<T>(&conn, key).await);
+            }
+
+            Ok(batch)
+        })
+    }
+}
+
+impl<'a, T, P> Count<'a, T, P> for PostgresStore<T>
+where
+    T: Table,
+    P: Predicate<T> + Condition,
+{
+    type Output = FutureResult<'a, usize, count::Error>;
+
+    fn execute(&'a self, predicate: Option<P>) -> Self::Output {
+        let (condition, params) = optional(predicate.as_ref());
+        let sql = sql::count(T::NAME, condition.as_deref());
+
+        FutureResult::from_future(async move {
+            let conn = self.postgres.connect().await?;
+            let count = scalar::<i64>(&conn, &sql, params).await?;
+
+            Ok(count as usize)
+        })
+    }
+}
+
+impl<'a, T, P> Exists<'a, T, P> for PostgresStore<T>
+where
+    T: Table,
+    P: Predicate<T> + Condition,
+{
+    type Output = FutureResult<'a, bool, exists::Error>;
+
+    fn execute(&'a self, predicate: Option<P>) -> Self::Output {
+        let (condition, params) = optional(predicate.as_ref());
+        let sql = sql::exists(T::NAME, condition.as_deref());
+
+        FutureResult::from_future(async move {
+            let conn = self.postgres.connect().await?;
+
+            scalar::<bool>(&conn, &sql, params).await
+        })
+    }
+}
+
+impl<'a, T, P> Project<'a, T, P> for PostgresStore<T>
+where
+    T: Table,
+    P: Predicate<T> + Condition,
+{
+    type Output = FutureResult<'a, Records<'a, Row>, project::Error>;
+
+    fn execute(
+        &'a self,
+        projection: Projection,
+        predicate: Option<P>,
+        options: Options,
+    ) -> Self::Output {
+        let (condition, params) = optional(predicate.as_ref());
+        let columns = projected::<T>(&projection);
+
+        FutureResult::from_future(async move {
+            let sql =
+                sql::select(T::NAME, &columns?, condition.as_deref()) + &sql::options(&options);
+            let conn = self.postgres.connect().await?;
+            let rows = rows(&conn, &sql, params).await?;
+
+            Ok(Records::from_stream(iter(
+                rows.into_iter().map(Record::new),
+            )))
+        })
+    }
+}
+
+impl<'a, T, P> Aggregate<'a, T, P> for PostgresStore<T>
+where
+    T: Table,
+    P: Predicate<T> + Condition,
+{
+    type Output = FutureResult<'a, Records<'a, Row>, aggregate::Error>;
+
+    fn execute(
+        &'a self,
+        aggregation: Aggregation,
+        predicate: Option<P>,
+        options: Options,
+    ) -> Self::Output {
+        let (condition, params) = optional(predicate.as_ref());
+        let sql = sql::aggregate(T::NAME, &aggregation, condition.as_deref(), &options);
+
+        FutureResult::from_future(async move {
+            let conn = self.postgres.connect().await?;
+            let rows = rows(&conn, &sql, params).await?;
+
+            Ok(Records::from_stream(iter(
+                rows.into_iter().map(Record::new),
+            )))
+        })
+    }
+}
+
+impl<'a, T> Paginate<'a, T> for PostgresStore<T>
+where
+    T: Table + Keyed + 'a,
+    T::Key: Into<Value>,
+{
+    type Output = FutureResult<'a, Page<'a, T>, page::Error>;
+
+    fn execute(&'a self, options: Options, cursor: Option<Cursor>) -> Self::Output {
+        FutureResult::from_future(async move {
+            let conn = self.postgres.connect().await?;
+
+            paginate(&conn, options, cursor).await
+        })
+    }
+}
+
+impl<'a, T, P> With<'a, T, P> for PostgresStore<T>
+where
+    T: Table + Related + 'a,
+    P: Predicate<T> + Condition,
+{
+    type Output = FutureResult<'a, Records<'a, T>, with::Error>;
+
+    fn execute(
+        &'a self,
+        relations: Vec<String>,
+        predicate: Option<P>,
+        options: Options,
+    ) -> Self::Output {
+        let (condition, params) = optional(predicate.as_ref());
+        let sql =
+            sql::select(T::NAME, &columns::<T>(), condition.as_deref()) + &sql::options(&options);
+
+        FutureResult::from_future(async move {
+            let relations = resolve::<T>(&relations)?;
+
+            let conn = self.postgres.connect().await?;
+            let mut records = records::<T>(&conn, &sql, params).await?;
+
+            relate(&conn, &mut records, &relations).await?;
+
+            Ok(Records::from_stream(iter(records)))
+        })
+    }
+}
+
+pub(crate) async fn apply<T, M>(
+    client: &Client,
+    sql: &str,
+    params: Vec<Value>,
+    modify: &M,
+) -> Result<Vec<Record<T>>, StoreError>
+where
+    T: Table + Keyed,
+    T::Key: Into<Value>,
+    M: Modify<T>,
+{
+    let mut records = records::<T>(client, sql, params).await?;
+    let update = sql::update(T::NAME, T::COLUMNS, T::KEY);
+
+    for record in records.iter_mut() {
+        let key = SqlValue::from(record.key().into());
+
+        modify.modify(record);
+
+        let mut values = values(&**record);
+
+        values.push(key);
+        run(client, &update, values).await?;
+    }
+
+    Ok(records)
+}
+
+pub(crate) async fn remove<T>(client: &Client, key: T::Key) -> Result<Record<T>, StoreError>
+where
+    T: Table + Keyed,
+    T::Key: Into<Value>,
+{
+    let condition = format!("{} = $1", sql::ident(T::KEY));
+    let sql = sql::delete(T::NAME, Some(&condition)) + " RETURNING " + &columns::<T>();
+
+    records::<T>(client, &sql, vec![key.into()])
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| StoreError::not_found("Item does not exist"))
+}
+
+// A failed statement aborts the whole transaction, so each item of a batch
+// runs behind its own savepoint and only its own changes are undone.
+pub(crate) async fn isolate<F, R>(client: &Client, future: F) -> Result<R, StoreError>
+where
+    F: Future<Output = Result<R, StoreError>>,
+{
+    let savepoint = "brace_batch";
+
+    client
+        .batch_execute(&sql::savepoint(savepoint))
+        .await
+        .map_err(Error::from)?;
+
+    let result = future.await;
+    let sql = match result {
+        Ok(_) => sql::release(savepoint),
+        Err(_) => sql::rollback_to(savepoint) + "; " + &sql::release(savepoint),
+    };
+
+    client.batch_execute(&sql).await.map_err(Error::from)?;
+
+    result
+}
+
+pub(crate) async fn paginate<'a, T>(
+    client: &Client,
+    options: Options,
+    cursor: Option<Cursor>,
+) -> Result<Page<'a, T>, StoreError>
+where
+    T: Table + Keyed + 'a,
+    T::Key: Into<Value>,
+{
+    let (condition, params) = match &cursor {
+        Some(cursor) => (
+            Some(sql::after(&options, T::KEY, cursor, 1)),
+            cursor.values().iter().flatten().cloned().collect(),
+        ),
+        None => (None, Vec::new()),
+    };
+
+    let limit = options.limit();
+    let query = options.clone().order_by(Order::asc(T::KEY));
+    let query = match limit {
+        Some(limit) => query.with_limit(limit + 1),
+        None => query,
+    };
+
+    let sql = sql::select(T::NAME, &columns::<T>(), condition.as_deref()) + &sql::options(&query);
+    let mut records = records::<T>(client, &sql, params).await?;
+
+    let cursor = match limit {
+        Some(limit) if records.len() > limit => {
+            records.truncate(limit);
+
+            records
+                .last()
+                .map(|record| Cursor::from_item(&**record, &options))
+        }
+        _ => None,
+    };
+
+    Ok(Page::new(Records::from_stream(iter(records)), cursor))
+}
+
+pub(crate) async fn relate<T>(
+    client: &Client,
+    records: &mut [Record<T>],
+    relations: &[Relation],
+) -> Result<(), StoreError>
+where
+    T: Table,
+{
+    for relation in relations {
+        let lookup = relation.lookup(records.iter().map(|record| &**record));
+        let (targets, pivots) = match relation.pivot() {
+            Some(pivot) => {
+                let pivots = related(client, pivot.table(), &lookup).await?;
+                let lookup = relation.lookup_through(&pivots);
+
+                (related(client, relation.target(), &lookup).await?, pivots)
+            }
+            None => (
+                related(client, relation.target(), &lookup).await?,
+                Vec::new(),
+            ),
+        };
+        let groups = relation.group(&targets, &pivots);
+
+        for record in records.iter_mut() {
+            let rows = relation
+                .load(&**record, &groups)
+                .into_iter()
+                .cloned()
+                .collect();
+
+            record.set_related(relation.name(), rows);
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) async fn related(
+    client: &Client,
+    table: &str,
+    lookup: &Expr,
+) -> Result<Vec<Row>, StoreError> {
+    let (condition, params) = sql::condition(lookup, 1);
+    let sql = sql::select(table, "*", Some(&condition));
+
+    rows(client, &sql, params).await
+}
+
+pub(crate) async fn rows(
+    client: &Client,
+    sql: &str,
+    params: Vec<Value>,
+) -> Result<Vec<Row>, StoreError> {
+    let values = bind(params);
+    let rows = client
+        .query(sql, &self::params(&values))
+        .await
+        .map_err(Error::from)?;
+
+    rows.iter().map(row).collect()
+}
+
+pub(crate) async fn records<T>(
+    client: &Client,
+    sql: &str,
+    params: Vec<Value>,
+) -> Result<Vec<Record<T>>, StoreError>
+where
+    T: Table,
+{
+    rows(client, sql, params)
+        .await?
+        .into_iter()
+        .map(|row| T::from_row(row).map(Record::new))
+        .collect()
+}
+
+pub(crate) async fn scalar<S>(
+    client: &Client,
+    sql: &str,
+    params: Vec<Value>,
+) -> Result<S, StoreError>
+where
+    S: for<'r> tokio_postgres::types::FromSql<'r>,
+{
+    let values = bind(params);
+    let row = client
+        .query_one(sql, &self::params(&values))
+        .await
+        .map_err(Error::from)?;
+
+    Ok(row.try_get(0).map_err(Error::from)?)
+}
+
+pub(crate) async fn run(
+    client: &Client,
+    sql: &str,
+    values: Vec<SqlValue>,
+) -> Result<usize, StoreError> {
+    let count = client
+        .execute(sql, &params(&values))
+        .await
+        .map_err(Error::from)?;
+
+    Ok(count as usize)
+}
+
+pub(crate) fn columns<T>() -> String
+where
+    T: Table,
+{
+    sql::columns(&Projection::new(T::COLUMNS.iter().copied()))
+}
+
+// Projections are limited to the columns of the table so that a field name
+// cannot select anything the item itself does not expose.
+pub(crate) fn projected<T>(projection: &Projection) -> Result<String, StoreError>
+where
+    T: Table,
+{
+    if projection.fields().is_empty() {
+        return Ok(columns::<T>());
+    }
+
+    match projection
+        .fields()
+        .iter()
+        .find(|field| !T::COLUMNS.contains(&field.as_str()))
+    {
+        Some(field) => Err(StoreError::not_found(format!("Unknown column `{}`", field))),
+        None => Ok(sql::columns(projection)),
+    }
+}
+
+pub(crate) fn values<T>(item: &T) -> Vec<SqlValue>
+where
+    T: Table,
+{
+    T::COLUMNS
+        .iter()
+        .map(|column| SqlValue::from(item.field(column)))
+        .collect()
+}
+
+pub(crate) fn condition<P>(predicate: &P, start: usize) -> (Option<String>, Vec<Value>)
+where
+    P: Condition,
+{
+    match predicate.expr() {
+        Some(expr) => {
+            let (condition, params) = sql::condition(expr, start);
+
+            (Some(condition), params)
+        }
+        None => (None, Vec::new()),
+    }
+}
+
+pub(crate) fn optional<P>(predicate: Option<&P>) -> (Option<String>, Vec<Value>)
+where
+    P: Condition,
+{
+    match predicate {
+        Some(predicate) => condition(predicate, 1),
+        None => (None, Vec::new()),
+    }
+}
+
+pub(crate) fn bind(params: Vec<Value>) -> Vec<SqlValue> {
+    params.into_iter().map(SqlValue::from).collect()
+}
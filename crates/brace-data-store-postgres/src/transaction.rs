@@ -0,0 +1,434 @@
+use std::marker::PhantomData;
+
+use futures::future::FutureExt;
+use futures::pin_mut;
+use futures::stream::{iter, Stream, StreamExt};
+use tokio_postgres::Client;
+
+use brace_util_future::result::FutureResult;
+
+use brace_data_store::query::{
+    aggregate, batch, count, delete, exists, filter, get, insert, page, project, select, update,
+    upsert, with,
+};
+use brace_data_store::transaction::{self, Begin, FutureTransaction, Isolation, Transaction};
+use brace_data_store::{
+    resolve, Aggregate, Aggregation, Batch, Count, Cursor, Delete, DeleteMany, Exists, Filter, Get,
+    Insert, InsertMany, Keyed, Modify, Options, Page, Paginate, Predicate, Project, Projection,
+    Record, Records, Related, Row, Select, Store, Update, Upsert, Value, With,
+};
+
+use crate::connection::PostgresConnection;
+use crate::error::Error;
+use crate::sql;
+use crate::store::{
+    apply, bind, columns, condition, isolate, optional, paginate, projected, records, relate,
+    remove, rows, run, scalar, values, PostgresStore,
+};
+use crate::table::{Condition, Table};
+use crate::Postgres;
+
+// A transaction holds a pooled connection until it finishes. Dropping it
+// without a commit or rollback queues a ROLLBACK on that connection before
+// it is returned to the pool.
+pub struct PostgresTransaction<'a, T> {
+    conn: PostgresConnection<'a>,
+    finished: bool,
+    marker: PhantomData<T>,
+}
+
+impl<'a, T> PostgresTransaction<'a, T> {
+    pub async fn begin(postgres: &'a Postgres, isolation: Isolation) -> Result<Self, Error> {
+        let conn = postgres.connect().await?;
+
+        conn.batch_execute(&sql::begin(isolation)).await?;
+
+        Ok(Self {
+            conn,
+            finished: false,
+            marker: PhantomData,
+        })
+    }
+
+    pub(crate) fn client(&self) -> &Client {
+        &self.conn
+    }
+
+    async fn finish(mut self, sql: &str) -> Result<(), Error> {
+        let result = self.conn.batch_execute(sql).await;
+
+        self.finished = true;
+
+        Ok(result?)
+    }
+}
+
+impl<T> Drop for PostgresTransaction<'_, T> {
+    fn drop(&mut self) {
+        if !self.finished {
+            self.conn.batch_execute("ROLLBACK").now_or_never();
+        }
+    }
+}
+
+impl<'a, T> Begin<'a> for PostgresStore<T>
+where
+    T: 'a,
+{
+    type Transaction = PostgresTransaction<'a, T>;
+    type Output = FutureResult<'a, PostgresTransaction<'a, T>, transaction::Error>;
+
+    fn execute(&'a self, isolation: Isolation) -> Self::Output {
+        FutureResult::from_future(async move {
+            Ok(PostgresTransaction::begin(self.postgres(), isolation).await?)
+        })
+    }
+}
+
+impl<T> Store for PostgresTransaction<'_, T> {
+    type Item = T;
+}
+
+impl<T> Transaction for PostgresTransaction<'_, T> {
+    fn commit<'a>(self) -> FutureTransaction<'a, ()>
+    where
+        Self: 'a,
+    {
+        FutureTransaction::new(async move { Ok(self.finish("COMMIT").await?) })
+    }
+
+    fn rollback<'a>(self) -> FutureTransaction<'a, ()>
+    where
+        Self: 'a,
+    {
+        FutureTransaction::new(async move { Ok(self.finish("ROLLBACK").await?) })
+    }
+
+    fn savepoint(&mut self, name: &str) -> FutureTransaction<'_, ()> {
+        let sql = sql::savepoint(name);
+
+        FutureTransaction::new(async move {
+            Ok(self.conn.batch_execute(&sql).await.map_err(Error::from)?)
+        })
+    }
+
+    fn rollback_to(&mut self, name: &str) -> FutureTransaction<'_, ()> {
+        let sql = sql::rollback_to(name);
+
+        FutureTransaction::new(async move {
+            Ok(self.conn.batch_execute(&sql).await.map_err(Error::from)?)
+        })
+    }
+
+    fn release(&mut self, name: &str) -> FutureTransaction<'_, ()> {
+        let sql = sql::release(name);
+
+        FutureTransaction::new(async move {
+            Ok(self.conn.batch_execute(&sql).await.map_err(Error::from)?)
+        })
+    }
+}
+
+impl<'a, T> Select<'a, T> for PostgresTransaction<'_, T>
+where
+    T: Table + 'a,
+{
+    type Output = FutureResult<'a, Records<'a, T>, select::Error>;
+
+    fn execute(&'a self, options: Options) -> Self::Output {
+        let sql = sql::select(T::NAME, &columns::<T>(), None) + &sql::options(&options);
+
+        FutureResult::from_future(async move {
+            let records = records::<T>(&self.conn, &sql, Vec::new()).await?;
+
+            Ok(Records::from_stream(iter(records)))
+        })
+    }
+}
+
+impl<'a, T, P> Filter<'a, T, P> for PostgresTransaction<'_, T>
+where
+    T: Table + 'a,
+    P: Predicate<T> + Condition,
+{
+    type Output = FutureResult<'a, Records<'a, T>, filter::Error>;
+
+    fn execute(&'a self, predicate: P, options: Options) -> Self::Output {
+        let (condition, params) = condition(&predicate, 1);
+        let sql =
+            sql::select(T::NAME, &columns::<T>(), condition.as_deref()) + &sql::options(&options);
+
+        FutureResult::from_future(async move {
+            let records = records::<T>(&self.conn, &sql, params).await?;
+
+            Ok(Records::from_stream(iter(records)))
+        })
+    }
+}
+
+impl<'a, T> Get<'a, T> for PostgresTransaction<'_, T>
+where
+    T: Table + Keyed,
+    T::Key: Into<Value>,
+{
+    type Output = FutureResult<'a, Option<Record<T>>, get::Error>;
+
+    fn execute(&'a self, key: T::Key) -> Self::Output {
+        let condition = format!("{} = $1", sql::ident(T::KEY));
+        let sql = sql::select(T::NAME, &columns::<T>(), Some(&condition)) + " LIMIT 1";
+        let params = vec![key.into()];
+
+        FutureResult::from_future(async move {
+            let records = records::<T>(&self.conn, &sql, params).await?;
+
+            Ok(records.into_iter().next())
+        })
+    }
+}
+
+impl<'a, T> Insert<'a, T> for PostgresTransaction<'_, T>
+where
+    T: Table + 'a,
+{
+    type Output = FutureResult<'a, Record<T>, insert::Error>;
+
+    fn execute(&'a self, item: T) -> Self::Output {
+        let sql = sql::insert(T::NAME, T::COLUMNS);
+
+        FutureResult::from_future(async move {
+            run(&self.conn, &sql, values(&item)).await?;
+
+            Ok(Record::new(item))
+        })
+    }
+}
+
+impl<'a, T> Upsert<'a, T> for PostgresTransaction<'_, T>
+where
+    T: Table + Keyed + 'a,
+{
+    type Output = FutureResult<'a, Record<T>, upsert::Error>;
+
+    fn execute(&'a self, item: T) -> Self::Output {
+        let sql = sql::upsert(T::NAME, T::COLUMNS, T::KEY);
+
+        FutureResult::from_future(async move {
+            run(&self.conn, &sql, values(&item)).await?;
+
+            Ok(Record::new(item))
+        })
+    }
+}
+
+impl<'a, T, P, M> Update<'a, T, P, M> for PostgresTransaction<'_, T>
+where
+    T: Table + Keyed + 'a,
+    T::Key: Into<Value>,
+    P: Predicate<T> + Condition,
+    M: Modify<T> + 'a,
+{
+    type Output = FutureResult<'a, Records<'a, T>, update::Error>;
+
+    fn execute(&'a self, predicate: P, modify: M) -> Self::Output {
+        let (condition, params) = condition(&predicate, 1);
+        let sql = sql::select(T::NAME, &columns::<T>(), condition.as_deref());
+
+        FutureResult::from_future(async move {
+            let records = apply(&self.conn, &sql, params, &modify).await?;
+
+            Ok(Records::from_stream(iter(records)))
+        })
+    }
+}
+
+impl<'a, T, S> InsertMany<'a, T, S> for PostgresTransaction<'_, T>
+where
+    T: Table + 'a,
+    S: Stream<Item = T> + 'a,
+{
+    type Output = FutureResult<'a, Batch<T>, batch::Error>;
+
+    fn execute(&'a self, items: S) -> Self::Output {
+        let sql = sql::insert(T::NAME, T::COLUMNS);
+
+        FutureResult::from_future(async move {
+            let mut batch = Batch::new();
+
+            pin_mut!(items);
+
+            while let Some(item) = items.next().await {
+                let result = isolate(&self.conn, run(&self.conn, &sql, values(&item))).await;
+
+                batch.push(result.map(|_| Record::new(item)));
+            }
+
+            Ok(batch)
+        })
+    }
+}
+
+impl<'a, T, P> Delete<'a, T, P> for PostgresTransaction<'_, T>
+where
+    T: Table,
+    P: Predicate<T> + Condition,
+{
+    type Output = FutureResult<'a, usize, delete::Error>;
+
+    fn execute(&'a self, predicate: P) -> Self::Output {
+        let (condition, params) = condition(&predicate, 1);
+        let sql = sql::delete(T::NAME, condition.as_deref());
+
+        FutureResult::from_future(async move { run(&self.conn, &sql, bind(params)).await })
+    }
+}
+
+impl<'a, T, S> DeleteMany<'a, T, S> for PostgresTransaction<'_, T>
+where
+    T: Table + Keyed + 'a,
+    T::Key: Into<Value>,
+    S: Stream<Item = T::Key> + 'a,
+{
+    type Output = FutureResult<'a, Batch<T>, batch::Error>;
+
+    fn execute(&'a self, keys: S) -> Self::Output {
+        FutureResult::from_future(async move {
+            let mut batch = Batch::new();
+
+            pin_mut!(keys);
+
+            while let Some(key) = keys.next().await {
+                batch.push(isolate(&self.conn, remove::<T>(&self.conn, key)).await);
+            }
+
+            Ok(batch)
+        })
+    }
+}
+
+impl<'a, T, P> Count<'a, T, P> for PostgresTransaction<'_, T>
+where
+    T: Table,
+    P: Predicate<T> + Condition,
+{
+    type Output = FutureResult<'a, usize, count::Error>;
+
+    fn execute(&'a self, predicate: Option<P>) -> Self::Output {
+        let (condition, params) = optional(predicate.as_ref());
+        let sql = sql::count(T::NAME, condition.as_deref());
+
+        FutureResult::from_future(async move {
+            let count = scalar::<i64>(&self.conn, &sql, params).await?;
+
+            Ok(count as usize)
+        })
+    }
+}
+
+impl<'a, T, P> Exists<'a, T, P> for PostgresTransaction<'_, T>
+where
+    T: Table,
+    P: Predicate<T> + Condition,
+{
+    type Output = FutureResult<'a, bool, exists::Error>;
+
+    fn execute(&'a self, predicate: Option<P>) -> Self::Output {
+        let (condition, params) = optional(predicate.as_ref());
+        let sql = sql::exists(T::NAME, condition.as_deref());
+
+        FutureResult::from_future(async move { scalar::<bool>(&self.conn, &sql, params).await })
+    }
+}
+
+impl<'a, T, P> Project<'a, T, P> for PostgresTransaction<'_, T>
+where
+    T: Table,
+    P: Predicate<T> + Condition,
+{
+    type Output = FutureResult<'a, Records<'a, Row>, project::Error>;
+
+    fn execute(
+        &'a self,
+        projection: Projection,
+        predicate: Option<P>,
+        options: Options,
+    ) -> Self::Output {
+        let (condition, params) = optional(predicate.as_ref());
+        let columns = projected::<T>(&projection);
+
+        FutureResult::from_future(async move {
+            let sql =
+                sql::select(T::NAME, &columns?, condition.as_deref()) + &sql::options(&options);
+            let rows = rows(&self.conn, &sql, params).await?;
+
+            Ok(Records::from_stream(iter(
+                rows.into_iter().map(Record::new),
+            )))
+        })
+    }
+}
+
+impl<'a, T, P> Aggregate<'a, T, P> for PostgresTransaction<'_, T>
+where
+    T: Table,
+    P: Predicate<T> + Condition,
+{
+    type Output = FutureResult<'a, Records<'a, Row>, aggregate::Error>;
+
+    fn execute(
+        &'a self,
+        aggregation: Aggregation,
+        predicate: Option<P>,
+        options: Options,
+    ) -> Self::Output {
+        let (condition, params) = optional(predicate.as_ref());
+        let sql = sql::aggregate(T::NAME, &aggregation, condition.as_deref(), &options);
+
+        FutureResult::from_future(async move {
+            let rows = rows(&self.conn, &sql, params).await?;
+
+            Ok(Records::from_stream(iter(
+                rows.into_iter().map(Record::new),
+            )))
+        })
+    }
+}
+
+impl<'a, T> Paginate<'a, T> for PostgresTransaction<'_, T>
+where
+    T: Table + Keyed + 'a,
+    T::Key: Into<Value>,
+{
+    type Output = FutureResult<'a, Page<'a, T>, page::Error>;
+
+    fn execute(&'a self, options: Options, cursor: Option<Cursor>) -> Self::Output {
+        FutureResult::from_future(async move { paginate(&self.conn, options, cursor).await })
+    }
+}
+
+impl<'a, T, P> With<'a, T, P> for PostgresTransaction<'_, T>
+where
+    T: Table + Related + 'a,
+    P: Predicate<T> + Condition,
+{
+    type Output = FutureResult<'a, Records<'a, T>, with::Error>;
+
+    fn execute(
+        &'a self,
+        relations: Vec<String>,
+        predicate: Option<P>,
+        options: Options,
+    ) -> Self::Output {
+        let (condition, params) = optional(predicate.as_ref());
+        let sql =
+            sql::select(T::NAME, &columns::<T>(), condition.as_deref()) + &sql::options(&options);
+
+        FutureResult::from_future(async move {
+            let relations = resolve::<T>(&relations)?;
+            let mut records = records::<T>(&self.conn, &sql, params).await?;
+
+            relate(&self.conn, &mut records, &relations).await?;
+
+            Ok(Records::from_stream(iter(records)))
+        })
+    }
+}
@@ -0,0 +1,176 @@
+use std::convert::TryFrom;
+use std::error::Error as StdError;
+
+use bytes::BytesMut;
+use tokio_postgres::types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use brace_data_store::{Error as StoreError, ErrorKind, Row, Value};
+
+use crate::error::Error;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SqlValue(pub Option<Value>);
+
+impl SqlValue {
+    pub fn into_inner(self) -> Option<Value> {
+        self.0
+    }
+}
+
+impl From<Value> for SqlValue {
+    fn from(value: Value) -> Self {
+        Self(Some(value))
+    }
+}
+
+impl From<Option<Value>> for SqlValue {
+    fn from(value: Option<Value>) -> Self {
+        Self(value)
+    }
+}
+
+impl ToSql for SqlValue {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn StdError + Sync + Send>> {
+        let value = match &self.0 {
+            Some(value) => value,
+            None => return Ok(IsNull::Yes),
+        };
+
+        match (value, ty) {
+            (Value::Bool(value), &Type::BOOL) => value.to_sql(ty, out),
+            (Value::Integer(value), &Type::INT2) => i16::try_from(*value)?.to_sql(ty, out),
+            (Value::Integer(value), &Type::INT4) => i32::try_from(*value)?.to_sql(ty, out),
+            (Value::Integer(value), &Type::INT8) => value.to_sql(ty, out),
+            (Value::Integer(value), &Type::FLOAT8) => (*value as f64).to_sql(ty, out),
+            (Value::Float(value), &Type::FLOAT4) => (*value as f32).to_sql(ty, out),
+            (Value::Float(value), &Type::FLOAT8) => value.to_sql(ty, out),
+            (Value::Text(value), ty) if <&str as ToSql>::accepts(ty) => {
+                value.as_str().to_sql(ty, out)
+            }
+            (value, ty) => Err(format!("cannot convert {:?} to {}", value, ty).into()),
+        }
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        match *ty {
+            Type::BOOL | Type::INT2 | Type::INT4 | Type::INT8 | Type::FLOAT4 | Type::FLOAT8 => true,
+            ref ty => <&str as ToSql>::accepts(ty),
+        }
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for SqlValue {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn StdError + Sync + Send>> {
+        let value = match *ty {
+            Type::BOOL => Value::Bool(bool::from_sql(ty, raw)?),
+            Type::INT2 => Value::Integer(i16::from_sql(ty, raw)?.into()),
+            Type::INT4 => Value::Integer(i32::from_sql(ty, raw)?.into()),
+            Type::INT8 => Value::Integer(i64::from_sql(ty, raw)?),
+            Type::FLOAT4 => Value::Float(f32::from_sql(ty, raw)?.into()),
+            Type::FLOAT8 => Value::Float(f64::from_sql(ty, raw)?),
+            _ => Value::Text(<&str>::from_sql(ty, raw)?.to_owned()),
+        };
+
+        Ok(Self(Some(value)))
+    }
+
+    fn from_sql_null(_: &Type) -> Result<Self, Box<dyn StdError + Sync + Send>> {
+        Ok(Self(None))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        match *ty {
+            Type::BOOL | Type::INT2 | Type::INT4 | Type::INT8 | Type::FLOAT4 | Type::FLOAT8 => true,
+            ref ty => <&str as FromSql>::accepts(ty),
+        }
+    }
+}
+
+pub fn params(values: &[SqlValue]) -> Vec<&(dyn ToSql + Sync)> {
+    values
+        .iter()
+        .map(|value| value as &(dyn ToSql + Sync))
+        .collect()
+}
+
+pub fn row(row: &tokio_postgres::Row) -> Result<Row, StoreError> {
+    let mut item = Row::new();
+
+    for (index, column) in row.columns().iter().enumerate() {
+        let value = row
+            .try_get::<_, SqlValue>(index)
+            .map_err(|err| StoreError::from_source(ErrorKind::Serialization, Error::from(err)))?;
+
+        if let Some(value) = value.into_inner() {
+            item.insert(column.name(), value);
+        }
+    }
+
+    Ok(item)
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use tokio_postgres::types::{FromSql, IsNull, ToSql, Type};
+
+    use brace_data_store::Value;
+
+    use super::SqlValue;
+
+    fn encode(value: SqlValue, ty: &Type) -> Option<Vec<u8>> {
+        let mut out = BytesMut::new();
+
+        match value.to_sql_checked(ty, &mut out).ok()? {
+            IsNull::Yes => Some(Vec::new()),
+            IsNull::No => Some(out.to_vec()),
+        }
+    }
+
+    #[test]
+    fn test_value_to_sql() {
+        assert_eq!(
+            encode(Value::Integer(7).into(), &Type::INT4),
+            Some(7i32.to_be_bytes().to_vec())
+        );
+        assert_eq!(
+            encode(Value::Integer(7).into(), &Type::INT8),
+            Some(7i64.to_be_bytes().to_vec())
+        );
+        assert_eq!(
+            encode(Value::from("abc").into(), &Type::TEXT),
+            Some(b"abc".to_vec())
+        );
+        assert_eq!(encode(SqlValue(None), &Type::INT4), Some(Vec::new()));
+        assert_eq!(encode(Value::Integer(1 << 40).into(), &Type::INT4), None);
+        assert_eq!(encode(Value::from("abc").into(), &Type::INT4), None);
+        assert_eq!(encode(Value::Integer(1).into(), &Type::BYTEA), None);
+    }
+
+    #[test]
+    fn test_value_from_sql() {
+        assert_eq!(
+            SqlValue::from_sql(&Type::INT2, &3i16.to_be_bytes()).unwrap(),
+            SqlValue(Some(Value::Integer(3)))
+        );
+        assert_eq!(
+            SqlValue::from_sql(&Type::FLOAT8, &1.5f64.to_be_bytes()).unwrap(),
+            SqlValue(Some(Value::Float(1.5)))
+        );
+        assert_eq!(
+            SqlValue::from_sql(&Type::VARCHAR, b"abc").unwrap(),
+            SqlValue(Some(Value::from("abc")))
+        );
+        assert_eq!(
+            SqlValue::from_sql_null(&Type::TEXT).unwrap(),
+            SqlValue(None)
+        );
+        assert!(!<SqlValue as FromSql>::accepts(&Type::BYTEA));
+    }
+}
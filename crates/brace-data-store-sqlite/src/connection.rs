@@ -0,0 +1,35 @@
+use std::ops::{Deref, DerefMut};
+
+use r2d2::PooledConnection;
+use r2d2_sqlite::SqliteConnectionManager;
+use rusqlite::Connection as Client;
+
+use brace_data_store::connection::Connection;
+
+use crate::error::Error;
+
+pub struct SqliteConnection(PooledConnection<SqliteConnectionManager>);
+
+impl SqliteConnection {
+    pub fn new(conn: PooledConnection<SqliteConnectionManager>) -> Self {
+        Self(conn)
+    }
+}
+
+impl Connection for SqliteConnection {
+    type Error = Error;
+}
+
+impl Deref for SqliteConnection {
+    type Target = Client;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.deref()
+    }
+}
+
+impl DerefMut for SqliteConnection {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.0.deref_mut()
+    }
+}
@@ -0,0 +1,112 @@
+use std::fmt::{self, Display};
+
+use rusqlite::ErrorCode;
+
+use brace_data_store::error::{Error as StoreError, ErrorKind};
+
+#[derive(Debug)]
+pub enum Error {
+    Pool(r2d2::Error),
+    User(rusqlite::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Pool(err) => err.fmt(f),
+            Self::User(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Pool(err) => Some(err),
+            Self::User(err) => Some(err),
+        }
+    }
+}
+
+impl From<rusqlite::Error> for Error {
+    fn from(error: rusqlite::Error) -> Self {
+        Self::User(error)
+    }
+}
+
+impl From<r2d2::Error> for Error {
+    fn from(error: r2d2::Error) -> Self {
+        Self::Pool(error)
+    }
+}
+
+impl From<Error> for StoreError {
+    fn from(error: Error) -> Self {
+        let kind = match &error {
+            Error::Pool(err) => pool(err),
+            Error::User(err) => kind(err),
+        };
+
+        StoreError::from_source(kind, error)
+    }
+}
+
+// The pool only fails by timing out, and it appends the last error from
+// opening a connection to the message when there was one.
+fn pool(error: &r2d2::Error) -> ErrorKind {
+    match error.to_string().as_str() {
+        "timed out waiting for connection" => ErrorKind::Timeout,
+        _ => ErrorKind::Connection,
+    }
+}
+
+fn kind(error: &rusqlite::Error) -> ErrorKind {
+    match error {
+        rusqlite::Error::SqliteFailure(err, _) => match err.code {
+            ErrorCode::ConstraintViolation => ErrorKind::ConstraintViolation,
+            ErrorCode::DatabaseBusy | ErrorCode::DatabaseLocked => ErrorKind::Conflict,
+            ErrorCode::CannotOpen | ErrorCode::NotADatabase => ErrorKind::Connection,
+            ErrorCode::TypeMismatch => ErrorKind::Serialization,
+            _ => ErrorKind::Backend,
+        },
+        rusqlite::Error::QueryReturnedNoRows => ErrorKind::NotFound,
+        rusqlite::Error::FromSqlConversionFailure(..)
+        | rusqlite::Error::IntegralValueOutOfRange(..)
+        | rusqlite::Error::InvalidColumnType(..)
+        | rusqlite::Error::Utf8Error(_) => ErrorKind::Serialization,
+        _ => ErrorKind::Backend,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use r2d2::Pool;
+    use r2d2_sqlite::SqliteConnectionManager;
+
+    use brace_data_store::error::{Error as StoreError, ErrorKind};
+
+    use super::Error;
+
+    fn pool(manager: SqliteConnectionManager) -> Pool<SqliteConnectionManager> {
+        Pool::builder()
+            .max_size(1)
+            .connection_timeout(Duration::from_millis(50))
+            .build_unchecked(manager)
+    }
+
+    #[test]
+    fn test_error_pool_kind() {
+        let missing = pool(SqliteConnectionManager::file("/nonexistent/brace/store.db"));
+        let error = StoreError::from(Error::from(missing.get().err().unwrap()));
+
+        assert_eq!(error.kind(), ErrorKind::Connection);
+
+        let busy = pool(SqliteConnectionManager::memory());
+        let _conn = busy.get().unwrap();
+        let error = StoreError::from(Error::from(busy.get().err().unwrap()));
+
+        assert_eq!(error.kind(), ErrorKind::Timeout);
+    }
+}
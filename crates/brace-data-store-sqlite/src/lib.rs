@@ -0,0 +1,72 @@
+use std::path::Path;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use r2d2::Pool;
+use r2d2_sqlite::SqliteConnectionManager;
+use rusqlite::OpenFlags;
+
+use brace_data_store::connection::FutureConnection;
+
+pub use self::connection::SqliteConnection;
+pub use self::error::Error;
+pub use self::store::SqliteStore;
+pub use self::table::{Condition, Table};
+pub use self::transaction::SqliteTransaction;
+
+pub mod connection;
+pub mod error;
+pub mod sql;
+pub mod store;
+pub mod table;
+pub mod transaction;
+pub mod value;
+
+static MEMORY: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Clone, Debug)]
+pub struct Sqlite(Pool<SqliteConnectionManager>);
+
+impl Sqlite {
+    pub fn new<P>(path: P) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        Self(Pool::builder().build_unchecked(SqliteConnectionManager::file(path)))
+    }
+
+    // Every pooled connection opens the same named shared cache database,
+    // which lives for as long as the pool keeps a connection open.
+    pub fn memory() -> Self {
+        let name = format!(
+            "file:brace-memory-{}-{}?mode=memory&cache=shared",
+            process::id(),
+            MEMORY.fetch_add(1, Ordering::Relaxed)
+        );
+        let flags = OpenFlags::SQLITE_OPEN_READ_WRITE
+            | OpenFlags::SQLITE_OPEN_CREATE
+            | OpenFlags::SQLITE_OPEN_URI;
+
+        Self(
+            Pool::builder()
+                .idle_timeout(None)
+                .max_lifetime(None)
+                .build_unchecked(SqliteConnectionManager::file(name).with_flags(flags)),
+        )
+    }
+
+    pub fn connect(&self) -> FutureConnection<'_, SqliteConnection> {
+        match self.get() {
+            Ok(conn) => FutureConnection::ready(conn),
+            Err(err) => FutureConnection::error(err),
+        }
+    }
+
+    pub fn store<T>(&self) -> SqliteStore<T> {
+        SqliteStore::new(self.clone())
+    }
+
+    pub(crate) fn get(&self) -> Result<SqliteConnection, Error> {
+        Ok(SqliteConnection::new(self.0.get()?))
+    }
+}
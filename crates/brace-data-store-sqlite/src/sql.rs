@@ -0,0 +1,402 @@
+use brace_data_store::query::expr::Expr;
+use brace_data_store::query::options::{Direction, Nulls, Options, Order};
+use brace_data_store::query::page::Cursor;
+use brace_data_store::query::project::Projection;
+use brace_data_store::transaction::Isolation;
+use brace_data_store::Value;
+
+pub fn ident(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+pub fn order(order: &Order) -> String {
+    let direction = match order.direction() {
+        Direction::Ascending => "ASC",
+        Direction::Descending => "DESC",
+    };
+
+    let nulls = match order.nulls() {
+        Nulls::First => "NULLS FIRST",
+        Nulls::Last => "NULLS LAST",
+    };
+
+    format!("{} {} {}", ident(order.field()), direction, nulls)
+}
+
+pub fn options(options: &Options) -> String {
+    let mut sql = String::new();
+
+    if !options.order().is_empty() {
+        let order = options.order().iter().map(order).collect::<Vec<_>>();
+
+        sql.push_str(" ORDER BY ");
+        sql.push_str(&order.join(", "));
+    }
+
+    match (options.limit(), options.offset()) {
+        (Some(limit), Some(offset)) => sql.push_str(&format!(" LIMIT {} OFFSET {}", limit, offset)),
+        (Some(limit), None) => sql.push_str(&format!(" LIMIT {}", limit)),
+        (None, Some(offset)) => sql.push_str(&format!(" LIMIT -1 OFFSET {}", offset)),
+        (None, None) => {}
+    }
+
+    sql
+}
+
+pub fn after(options: &Options, key: &str, cursor: &Cursor, start: usize) -> String {
+    let mut param = start;
+    let mut equal = Vec::new();
+    let mut branches = Vec::new();
+    let key = Order::asc(key).nulls_first();
+
+    for (order, value) in options
+        .order()
+        .iter()
+        .chain(Some(&key))
+        .zip(cursor.values())
+    {
+        let column = ident(order.field());
+
+        let (greater, same) = match value {
+            Some(_) => {
+                let operator = match order.direction() {
+                    Direction::Ascending => ">",
+                    Direction::Descending => "<",
+                };
+
+                let greater = match order.nulls() {
+                    Nulls::First => format!("{} {} ?{}", column, operator, param),
+                    Nulls::Last => {
+                        format!("({} {} ?{} OR {} IS NULL)", column, operator, param, column)
+                    }
+                };
+
+                let same = format!("{} = ?{}", column, param);
+
+                param += 1;
+
+                (greater, same)
+            }
+            None => {
+                let greater = match order.nulls() {
+                    Nulls::First => format!("{} IS NOT NULL", column),
+                    Nulls::Last => "0".to_owned(),
+                };
+
+                (greater, format!("{} IS NULL", column))
+            }
+        };
+
+        let mut branch = equal.clone();
+
+        branch.push(greater);
+        branches.push(format!("({})", branch.join(" AND ")));
+        equal.push(same);
+    }
+
+    if branches.is_empty() {
+        return "1".to_owned();
+    }
+
+    format!("({})", branches.join(" OR "))
+}
+
+pub fn condition(expr: &Expr, start: usize) -> (String, Vec<Value>) {
+    let mut params = Vec::new();
+    let sql = compile(expr, start, &mut params);
+
+    (sql, params)
+}
+
+fn compile(expr: &Expr, start: usize, params: &mut Vec<Value>) -> String {
+    let mut param = |value: &Value| {
+        params.push(value.clone());
+        format!("?{}", start + params.len() - 1)
+    };
+
+    match expr {
+        Expr::Eq(field, value) => format!("{} = {}", ident(field), param(value)),
+        Expr::Ne(field, value) => format!("{} <> {}", ident(field), param(value)),
+        Expr::Lt(field, value) => format!("{} < {}", ident(field), param(value)),
+        Expr::Le(field, value) => format!("{} <= {}", ident(field), param(value)),
+        Expr::Gt(field, value) => format!("{} > {}", ident(field), param(value)),
+        Expr::Ge(field, value) => format!("{} >= {}", ident(field), param(value)),
+        Expr::In(_, values) if values.is_empty() => "0".to_owned(),
+        Expr::In(field, values) => {
+            let values = values.iter().map(param).collect::<Vec<_>>();
+
+            format!("{} IN ({})", ident(field), values.join(", "))
+        }
+        Expr::Like(field, pattern) => {
+            let pattern = param(&Value::Text(pattern.clone()));
+
+            format!("{} LIKE {}", ident(field), pattern)
+        }
+        Expr::IsNull(field) => format!("{} IS NULL", ident(field)),
+        Expr::And(exprs) => junction(exprs, " AND ", "1", start, params),
+        Expr::Or(exprs) => junction(exprs, " OR ", "0", start, params),
+        Expr::Not(expr) => format!("NOT ({})", compile(expr, start, params)),
+    }
+}
+
+fn junction(
+    exprs: &[Expr],
+    separator: &str,
+    empty: &str,
+    start: usize,
+    params: &mut Vec<Value>,
+) -> String {
+    if exprs.is_empty() {
+        return empty.to_owned();
+    }
+
+    let exprs = exprs
+        .iter()
+        .map(|expr| format!("({})", compile(expr, start, params)))
+        .collect::<Vec<_>>();
+
+    exprs.join(separator)
+}
+
+pub fn columns(projection: &Projection) -> String {
+    if projection.fields().is_empty() {
+        return "*".to_owned();
+    }
+
+    list(projection.fields())
+}
+
+pub fn select(table: &str, columns: &str, condition: Option<&str>) -> String {
+    format!(
+        "SELECT {} FROM {}{}",
+        columns,
+        ident(table),
+        filter(condition)
+    )
+}
+
+pub fn insert<T>(table: &str, columns: &[T]) -> String
+where
+    T: AsRef<str>,
+{
+    format!(
+        "INSERT INTO {} ({}) VALUES ({})",
+        ident(table),
+        list(columns),
+        params(columns.len(), 1)
+    )
+}
+
+pub fn upsert<T>(table: &str, columns: &[T]) -> String
+where
+    T: AsRef<str>,
+{
+    format!(
+        "INSERT OR REPLACE INTO {} ({}) VALUES ({})",
+        ident(table),
+        list(columns),
+        params(columns.len(), 1)
+    )
+}
+
+pub fn update<T>(table: &str, columns: &[T], key: &str) -> String
+where
+    T: AsRef<str>,
+{
+    let columns = columns
+        .iter()
+        .enumerate()
+        .map(|(i, column)| format!("{} = ?{}", ident(column.as_ref()), i + 1))
+        .collect::<Vec<_>>();
+
+    format!(
+        "UPDATE {} SET {} WHERE {} = ?{}",
+        ident(table),
+        columns.join(", "),
+        ident(key),
+        columns.len() + 1
+    )
+}
+
+pub fn delete(table: &str, condition: Option<&str>) -> String {
+    format!("DELETE FROM {}{}", ident(table), filter(condition))
+}
+
+pub fn count(table: &str, condition: Option<&str>) -> String {
+    format!("SELECT count(*) FROM {}{}", ident(table), filter(condition))
+}
+
+pub fn exists(table: &str, condition: Option<&str>) -> String {
+    format!(
+        "SELECT EXISTS (SELECT 1 FROM {}{})",
+        ident(table),
+        filter(condition)
+    )
+}
+
+fn list<T>(columns: &[T]) -> String
+where
+    T: AsRef<str>,
+{
+    columns
+        .iter()
+        .map(|column| ident(column.as_ref()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn params(len: usize, start: usize) -> String {
+    (start..start + len)
+        .map(|param| format!("?{}", param))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub fn begin(isolation: Isolation) -> &'static str {
+    match isolation {
+        Isolation::Serializable => "BEGIN IMMEDIATE",
+        _ => "BEGIN DEFERRED",
+    }
+}
+
+pub fn savepoint(name: &str) -> String {
+    format!("SAVEPOINT {}", ident(name))
+}
+
+pub fn rollback_to(name: &str) -> String {
+    format!("ROLLBACK TO SAVEPOINT {}", ident(name))
+}
+
+pub fn release(name: &str) -> String {
+    format!("RELEASE SAVEPOINT {}", ident(name))
+}
+
+fn filter(condition: Option<&str>) -> String {
+    match condition {
+        Some(condition) => format!(" WHERE {}", condition),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use brace_data_store::query::expr::{field, Expr};
+    use brace_data_store::query::options::{Options, Order};
+    use brace_data_store::query::page::Cursor;
+    use brace_data_store::transaction::Isolation;
+    use brace_data_store::Value;
+
+    use super::{
+        after, begin, condition, count, delete, exists, insert, options, release, rollback_to,
+        savepoint, select, update, upsert,
+    };
+
+    #[test]
+    fn test_sql_options() {
+        assert_eq!(options(&Options::new()), "");
+        assert_eq!(
+            options(
+                &Options::new()
+                    .order_by(Order::desc("created"))
+                    .with_limit(10)
+                    .with_offset(20)
+            ),
+            " ORDER BY \"created\" DESC NULLS FIRST LIMIT 10 OFFSET 20"
+        );
+        assert_eq!(
+            options(&Options::new().with_offset(5)),
+            " LIMIT -1 OFFSET 5"
+        );
+    }
+
+    #[test]
+    fn test_sql_after() {
+        let options = Options::new()
+            .order_by(Order::desc("created"))
+            .order_by(Order::asc("id").nulls_first());
+
+        assert_eq!(
+            after(
+                &options,
+                "key",
+                &Cursor::new(vec![
+                    Some(Value::Integer(5)),
+                    Some(Value::Integer(9)),
+                    Some(Value::Integer(2))
+                ]),
+                1
+            ),
+            "((\"created\" < ?1) OR (\"created\" = ?1 AND \"id\" > ?2) \
+             OR (\"created\" = ?1 AND \"id\" = ?2 AND \"key\" > ?3))"
+        );
+        assert_eq!(
+            after(
+                &Options::new(),
+                "key",
+                &Cursor::new(vec![Some(Value::Integer(2))]),
+                1
+            ),
+            "((\"key\" > ?1))"
+        );
+        assert_eq!(
+            after(&Options::new(), "key", &Cursor::new(Vec::new()), 1),
+            "1"
+        );
+    }
+
+    #[test]
+    fn test_sql_condition() {
+        let expr = field("year")
+            .ge(1900)
+            .and(field("id").is_in(vec![1, 2]))
+            .and(!field("author").is_null());
+
+        assert_eq!(
+            condition(&expr, 1),
+            (
+                "(\"year\" >= ?1) AND (\"id\" IN (?2, ?3)) AND (NOT (\"author\" IS NULL))"
+                    .to_owned(),
+                vec![Value::Integer(1900), Value::Integer(1), Value::Integer(2)]
+            )
+        );
+        assert_eq!(
+            condition(&Expr::Or(Vec::new()), 1),
+            ("0".to_owned(), Vec::new())
+        );
+    }
+
+    #[test]
+    fn test_sql_statements() {
+        assert_eq!(
+            select("books", "*", Some("\"year\" > ?1")),
+            "SELECT * FROM \"books\" WHERE \"year\" > ?1"
+        );
+        assert_eq!(
+            insert("books", &["id", "title"]),
+            "INSERT INTO \"books\" (\"id\", \"title\") VALUES (?1, ?2)"
+        );
+        assert_eq!(
+            upsert("books", &["id", "title"]),
+            "INSERT OR REPLACE INTO \"books\" (\"id\", \"title\") VALUES (?1, ?2)"
+        );
+        assert_eq!(
+            update("books", &["id", "title"], "id"),
+            "UPDATE \"books\" SET \"id\" = ?1, \"title\" = ?2 WHERE \"id\" = ?3"
+        );
+        assert_eq!(delete("books", None), "DELETE FROM \"books\"");
+        assert_eq!(count("books", None), "SELECT count(*) FROM \"books\"");
+        assert_eq!(
+            exists("books", Some("\"year\" > ?1")),
+            "SELECT EXISTS (SELECT 1 FROM \"books\" WHERE \"year\" > ?1)"
+        );
+    }
+
+    #[test]
+    fn test_sql_savepoint() {
+        assert_eq!(begin(Isolation::Serializable), "BEGIN IMMEDIATE");
+        assert_eq!(begin(Isolation::ReadCommitted), "BEGIN DEFERRED");
+        assert_eq!(savepoint("one"), "SAVEPOINT \"one\"");
+        assert_eq!(rollback_to("one"), "ROLLBACK TO SAVEPOINT \"one\"");
+        assert_eq!(release("one"), "RELEASE SAVEPOINT \"one\"");
+    }
+}
@@ -0,0 +1,489 @@
+use std::marker::PhantomData;
+
+use futures::future::{ready, Ready};
+use futures::stream::iter;
+use rusqlite::types::Value as SqlValue;
+use rusqlite::{Connection as Client, TransactionBehavior};
+
+use brace_data_store::query::{
+    count, delete, exists, filter, get, insert, page, project, select, update, upsert, with,
+};
+use brace_data_store::{
+    relate, resolve, Count, Cursor, Delete, Error as StoreError, Exists, Expr, Filter, Get, Insert,
+    Keyed, Modify, Options, Order, Page, Paginate, Predicate, Project, Projection, Record, Records,
+    Related, Row, Select, Store, Update, Upsert, Value, With,
+};
+
+use crate::error::Error;
+use crate::sql;
+use crate::table::{Condition, Table};
+use crate::value::{from_sql, to_sql};
+use crate::Sqlite;
+
+// rusqlite is synchronous, so every operation runs on the calling thread when
+// it is executed and returns a ready future. Callers on an async executor
+// should move store work onto a blocking thread.
+pub struct SqliteStore<T> {
+    sqlite: Sqlite,
+    marker: PhantomData<T>,
+}
+
+impl<T> SqliteStore<T> {
+    pub fn new(sqlite: Sqlite) -> Self {
+        Self {
+            sqlite,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn sqlite(&self) -> &Sqlite {
+        &self.sqlite
+    }
+
+    fn query(&self, sql: &str, params: Vec<Value>) -> Result<Vec<Row>, StoreError> {
+        query(&*self.sqlite.get()?, sql, params)
+    }
+
+    fn scalar(&self, sql: &str, params: Vec<Value>) -> Result<i64, StoreError> {
+        scalar(&*self.sqlite.get()?, sql, params)
+    }
+
+    fn run(&self, sql: &str, params: Vec<SqlValue>) -> Result<usize, StoreError> {
+        run(&*self.sqlite.get()?, sql, params)
+    }
+}
+
+impl<T> SqliteStore<T>
+where
+    T: Table,
+{
+    fn records(&self, sql: &str, params: Vec<Value>) -> Result<Vec<Record<T>>, StoreError> {
+        records(&*self.sqlite.get()?, sql, params)
+    }
+
+    fn update<'a, M>(
+        &self,
+        sql: &str,
+        params: Vec<Value>,
+        modify: &M,
+    ) -> Result<Records<'a, T>, StoreError>
+    where
+        T: Keyed + 'a,
+        T::Key: Into<Value>,
+        M: Modify<T>,
+    {
+        let mut conn = self.sqlite.get()?;
+        let transaction = conn
+            .transaction_with_behavior(TransactionBehavior::Immediate)
+            .map_err(Error::from)?;
+        let mut records = records(&transaction, sql, params)?;
+
+        apply(&transaction, &mut records, modify)?;
+
+        transaction.commit().map_err(Error::from)?;
+
+        Ok(Records::from_stream(iter(records)))
+    }
+}
+
+impl<T> Clone for SqliteStore<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.sqlite.clone())
+    }
+}
+
+impl<T> Store for SqliteStore<T> {
+    type Item = T;
+}
+
+impl<'a, T> Select<'a, T> for SqliteStore<T>
+where
+    T: Table + 'a,
+{
+    type Output = Ready<Result<Records<'a, T>, select::Error>>;
+
+    fn execute(&'a self, options: Options) -> Self::Output {
+        let sql = sql::select(T::NAME, &columns::<T>(), None) + &sql::options(&options);
+
+        ready(
+            self.records(&sql, Vec::new())
+                .map(|records| Records::from_stream(iter(records))),
+        )
+    }
+}
+
+impl<'a, T, P> Filter<'a, T, P> for SqliteStore<T>
+where
+    T: Table + 'a,
+    P: Predicate<T> + Condition,
+{
+    type Output = Ready<Result<Records<'a, T>, filter::Error>>;
+
+    fn execute(&'a self, predicate: P, options: Options) -> Self::Output {
+        let (condition, params) = condition(&predicate, 1);
+        let sql =
+            sql::select(T::NAME, &columns::<T>(), condition.as_deref()) + &sql::options(&options);
+
+        ready(
+            self.records(&sql, params)
+                .map(|records| Records::from_stream(iter(records))),
+        )
+    }
+}
+
+impl<'a, T> Get<'a, T> for SqliteStore<T>
+where
+    T: Table + Keyed,
+    T::Key: Into<Value>,
+{
+    type Output = Ready<Result<Option<Record<T>>, get::Error>>;
+
+    fn execute(&'a self, key: T::Key) -> Self::Output {
+        let condition = format!("{} = ?1", sql::ident(T::KEY));
+        let sql = sql::select(T::NAME, &columns::<T>(), Some(&condition)) + " LIMIT 1";
+        let params = vec![key.into()];
+
+        ready(
+            self.records(&sql, params)
+                .map(|records| records.into_iter().next()),
+        )
+    }
+}
+
+impl<'a, T> Insert<'a, T> for SqliteStore<T>
+where
+    T: Table + 'a,
+{
+    type Output = Ready<Result<Record<T>, insert::Error>>;
+
+    fn execute(&'a self, item: T) -> Self::Output {
+        let sql = sql::insert(T::NAME, T::COLUMNS);
+
+        ready(self.run(&sql, values(&item)).map(|_| Record::new(item)))
+    }
+}
+
+impl<'a, T> Upsert<'a, T> for SqliteStore<T>
+where
+    T: Table + Keyed + 'a,
+{
+    type Output = Ready<Result<Record<T>, upsert::Error>>;
+
+    fn execute(&'a self, item: T) -> Self::Output {
+        let sql = sql::upsert(T::NAME, T::COLUMNS);
+
+        ready(self.run(&sql, values(&item)).map(|_| Record::new(item)))
+    }
+}
+
+impl<'a, T, P, M> Update<'a, T, P, M> for SqliteStore<T>
+where
+    T: Table + Keyed + 'a,
+    T::Key: Into<Value>,
+    P: Predicate<T> + Condition,
+    M: Modify<T> + 'a,
+{
+    type Output = Ready<Result<Records<'a, T>, update::Error>>;
+
+    fn execute(&'a self, predicate: P, modify: M) -> Self::Output {
+        let (condition, params) = condition(&predicate, 1);
+        let sql = sql::select(T::NAME, &columns::<T>(), condition.as_deref());
+
+        ready(self.update(&sql, params, &modify))
+    }
+}
+
+impl<'a, T, P> Delete<'a, T, P> for SqliteStore<T>
+where
+    T: Table,
+    P: Predicate<T> + Condition,
+{
+    type Output = Ready<Result<usize, delete::Error>>;
+
+    fn execute(&'a self, predicate: P) -> Self::Output {
+        let (condition, params) = condition(&predicate, 1);
+        let sql = sql::delete(T::NAME, condition.as_deref());
+
+        ready(self.run(&sql, bind(&params)))
+    }
+}
+
+impl<'a, T, P> Count<'a, T, P> for SqliteStore<T>
+where
+    T: Table,
+    P: Predicate<T> + Condition,
+{
+    type Output = Ready<Result<usize, count::Error>>;
+
+    fn execute(&'a self, predicate: Option<P>) -> Self::Output {
+        let (condition, params) = optional(predicate.as_ref());
+        let sql = sql::count(T::NAME, condition.as_deref());
+
+        ready(self.scalar(&sql, params).map(|count| count as usize))
+    }
+}
+
+impl<'a, T, P> Exists<'a, T, P> for SqliteStore<T>
+where
+    T: Table,
+    P: Predicate<T> + Condition,
+{
+    type Output = Ready<Result<bool, exists::Error>>;
+
+    fn execute(&'a self, predicate: Option<P>) -> Self::Output {
+        let (condition, params) = optional(predicate.as_ref());
+        let sql = sql::exists(T::NAME, condition.as_deref());
+
+        ready(self.scalar(&sql, params).map(|exists| exists != 0))
+    }
+}
+
+impl<'a, T> Paginate<'a, T> for SqliteStore<T>
+where
+    T: Table + Keyed + 'a,
+    T::Key: Into<Value>,
+{
+    type Output = Ready<Result<Page<'a, T>, page::Error>>;
+
+    fn execute(&'a self, options: Options, cursor: Option<Cursor>) -> Self::Output {
+        ready(
+            self.sqlite
+                .get()
+                .map_err(StoreError::from)
+                .and_then(|conn| paginate(&conn, options, cursor)),
+        )
+    }
+}
+
+impl<'a, T, P> Project<'a, T, P> for SqliteStore<T>
+where
+    T: Table,
+    P: Predicate<T> + Condition,
+{
+    type Output = Ready<Result<Records<'a, Row>, project::Error>>;
+
+    fn execute(
+        &'a self,
+        projection: Projection,
+        predicate: Option<P>,
+        options: Options,
+    ) -> Self::Output {
+        let (condition, params) = optional(predicate.as_ref());
+        let sql = sql::select(T::NAME, &sql::columns(&projection), condition.as_deref())
+            + &sql::options(&options);
+
+        ready(
+            self.query(&sql, params)
+                .map(|rows| Records::from_stream(iter(rows.into_iter().map(Record::new)))),
+        )
+    }
+}
+
+impl<'a, T, P> With<'a, T, P> for SqliteStore<T>
+where
+    T: Table + Related + 'a,
+    P: Predicate<T> + Condition,
+{
+    type Output = Ready<Result<Records<'a, T>, with::Error>>;
+
+    fn execute(
+        &'a self,
+        relations: Vec<String>,
+        predicate: Option<P>,
+        options: Options,
+    ) -> Self::Output {
+        let (condition, params) = optional(predicate.as_ref());
+        let sql =
+            sql::select(T::NAME, &columns::<T>(), condition.as_deref()) + &sql::options(&options);
+
+        ready(
+            self.sqlite
+                .get()
+                .map_err(StoreError::from)
+                .and_then(|conn| eager(&conn, &sql, params, &relations))
+                .map(|records| Records::from_stream(iter(records))),
+        )
+    }
+}
+
+pub(crate) fn query(conn: &Client, sql: &str, params: Vec<Value>) -> Result<Vec<Row>, StoreError> {
+    let mut stmt = conn.prepare(sql).map_err(Error::from)?;
+    let names = stmt
+        .column_names()
+        .into_iter()
+        .map(String::from)
+        .collect::<Vec<_>>();
+    let mut rows = stmt.query(bind(&params)).map_err(Error::from)?;
+    let mut result = Vec::new();
+
+    while let Some(row) = rows.next().map_err(Error::from)? {
+        let mut item = Row::new();
+
+        for (i, name) in names.iter().enumerate() {
+            if let Some(value) = from_sql(row.get_raw_checked(i).map_err(Error::from)?)? {
+                item.insert(name.as_str(), value);
+            }
+        }
+
+        result.push(item);
+    }
+
+    Ok(result)
+}
+
+pub(crate) fn scalar(conn: &Client, sql: &str, params: Vec<Value>) -> Result<i64, StoreError> {
+    Ok(conn
+        .query_row(sql, bind(&params), |row| row.get(0))
+        .map_err(Error::from)?)
+}
+
+pub(crate) fn run(conn: &Client, sql: &str, params: Vec<SqlValue>) -> Result<usize, StoreError> {
+    Ok(conn.execute(sql, params).map_err(Error::from)?)
+}
+
+pub(crate) fn records<T>(
+    conn: &Client,
+    sql: &str,
+    params: Vec<Value>,
+) -> Result<Vec<Record<T>>, StoreError>
+where
+    T: Table,
+{
+    query(conn, sql, params)?
+        .into_iter()
+        .map(|row| T::from_row(row).map(Record::new))
+        .collect()
+}
+
+pub(crate) fn eager<T>(
+    conn: &Client,
+    sql: &str,
+    params: Vec<Value>,
+    names: &[String],
+) -> Result<Vec<Record<T>>, StoreError>
+where
+    T: Table + Related,
+{
+    let relations = resolve::<T>(names)?;
+    let mut records = records::<T>(conn, sql, params)?;
+
+    relate(&mut records, &relations, &|table: &str, lookup: &Expr| {
+        let (condition, params) = sql::condition(lookup, 1);
+
+        query(conn, &sql::select(table, "*", Some(&condition)), params)
+    })?;
+
+    Ok(records)
+}
+
+pub(crate) fn values<T>(item: &T) -> Vec<SqlValue>
+where
+    T: Table,
+{
+    T::COLUMNS
+        .iter()
+        .map(|column| to_sql(item.field(column).as_ref()))
+        .collect()
+}
+
+pub(crate) fn apply<T, M>(
+    conn: &Client,
+    records: &mut [Record<T>],
+    modify: &M,
+) -> Result<(), StoreError>
+where
+    T: Table + Keyed,
+    T::Key: Into<Value>,
+    M: Modify<T>,
+{
+    let update = sql::update(T::NAME, T::COLUMNS, T::KEY);
+
+    for record in records.iter_mut() {
+        let key = to_sql(Some(&record.key().into()));
+
+        modify.modify(record);
+
+        let mut values = values(&**record);
+
+        values.push(key);
+        conn.execute(&update, values).map_err(Error::from)?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn paginate<'a, T>(
+    conn: &Client,
+    options: Options,
+    cursor: Option<Cursor>,
+) -> Result<Page<'a, T>, StoreError>
+where
+    T: Table + Keyed + 'a,
+    T::Key: Into<Value>,
+{
+    let (condition, params) = match &cursor {
+        Some(cursor) => (
+            Some(sql::after(&options, T::KEY, cursor, 1)),
+            cursor.values().iter().flatten().cloned().collect(),
+        ),
+        None => (None, Vec::new()),
+    };
+
+    let limit = options.limit();
+    let query = options.clone().order_by(Order::asc(T::KEY));
+    let query = match limit {
+        Some(limit) => query.with_limit(limit + 1),
+        None => query,
+    };
+
+    let sql = sql::select(T::NAME, &columns::<T>(), condition.as_deref()) + &sql::options(&query);
+    let mut records = records(conn, &sql, params)?;
+
+    let cursor = match limit {
+        Some(limit) if records.len() > limit => {
+            records.truncate(limit);
+
+            records
+                .last()
+                .map(|record| Cursor::from_item(record, &options))
+        }
+        _ => None,
+    };
+
+    Ok(Page::new(Records::from_stream(iter(records)), cursor))
+}
+
+pub(crate) fn columns<T>() -> String
+where
+    T: Table,
+{
+    sql::columns(&Projection::new(T::COLUMNS.iter().copied()))
+}
+
+pub(crate) fn condition<P>(predicate: &P, start: usize) -> (Option<String>, Vec<Value>)
+where
+    P: Condition,
+{
+    match predicate.expr() {
+        Some(expr) => {
+            let (condition, params) = sql::condition(expr, start);
+
+            (Some(condition), params)
+        }
+        None => (None, Vec::new()),
+    }
+}
+
+pub(crate) fn optional<P>(predicate: Option<&P>) -> (Option<String>, Vec<Value>)
+where
+    P: Condition,
+{
+    match predicate {
+        Some(predicate) => condition(predicate, 1),
+        None => (None, Vec::new()),
+    }
+}
+
+pub(crate) fn bind(params: &[Value]) -> Vec<SqlValue> {
+    params.iter().map(|value| to_sql(Some(value))).collect()
+}
@@ -0,0 +1,31 @@
+use brace_data_store::{Always, Error, Expr, Fields, Row};
+
+pub trait Table: Fields + Sized {
+    const NAME: &'static str;
+    const KEY: &'static str;
+    const COLUMNS: &'static [&'static str];
+
+    fn from_row(row: Row) -> Result<Self, Error>;
+}
+
+pub trait Condition {
+    fn expr(&self) -> Option<&Expr>;
+}
+
+impl Condition for Expr {
+    fn expr(&self) -> Option<&Expr> {
+        Some(self)
+    }
+}
+
+impl Condition for &Expr {
+    fn expr(&self) -> Option<&Expr> {
+        Some(self)
+    }
+}
+
+impl Condition for Always {
+    fn expr(&self) -> Option<&Expr> {
+        None
+    }
+}
@@ -0,0 +1,307 @@
+use std::marker::PhantomData;
+
+use futures::future::{ready, Ready};
+use futures::stream::iter;
+
+use brace_data_store::query::{
+    count, delete, exists, filter, get, insert, page, project, select, update, upsert, with,
+};
+use brace_data_store::transaction::{self, Begin, FutureTransaction, Isolation, Transaction};
+use brace_data_store::{
+    Count, Cursor, Delete, Error as StoreError, Exists, Filter, Get, Insert, Keyed, Modify,
+    Options, Page, Paginate, Predicate, Project, Projection, Record, Records, Related, Row, Select,
+    Store, Update, Upsert, Value, With,
+};
+
+use crate::connection::SqliteConnection;
+use crate::error::Error;
+use crate::sql;
+use crate::store::{
+    apply, bind, columns, condition, eager, optional, paginate, query, records, run, scalar,
+    values, SqliteStore,
+};
+use crate::table::{Condition, Table};
+use crate::Sqlite;
+
+// A transaction holds a pooled connection until it finishes. Dropping it
+// without a commit or rollback rolls it back before the connection is
+// returned to the pool.
+pub struct SqliteTransaction<T> {
+    conn: SqliteConnection,
+    marker: PhantomData<T>,
+}
+
+impl<T> SqliteTransaction<T> {
+    pub fn begin(sqlite: &Sqlite, isolation: Isolation) -> Result<Self, Error> {
+        let conn = sqlite.get()?;
+
+        conn.execute_batch(sql::begin(isolation))?;
+
+        Ok(Self {
+            conn,
+            marker: PhantomData,
+        })
+    }
+
+    fn execute(&self, sql: &str) -> Result<(), StoreError> {
+        Ok(self.conn.execute_batch(sql).map_err(Error::from)?)
+    }
+}
+
+impl<T> Drop for SqliteTransaction<T> {
+    fn drop(&mut self) {
+        if !self.conn.is_autocommit() {
+            self.conn.execute_batch("ROLLBACK").ok();
+        }
+    }
+}
+
+impl<'a, T> Begin<'a> for SqliteStore<T>
+where
+    T: 'a,
+{
+    type Transaction = SqliteTransaction<T>;
+    type Output = Ready<Result<SqliteTransaction<T>, transaction::Error>>;
+
+    fn execute(&'a self, isolation: Isolation) -> Self::Output {
+        ready(SqliteTransaction::begin(self.sqlite(), isolation).map_err(StoreError::from))
+    }
+}
+
+impl<T> Store for SqliteTransaction<T> {
+    type Item = T;
+}
+
+impl<T> Transaction for SqliteTransaction<T> {
+    fn commit<'a>(self) -> FutureTransaction<'a, ()>
+    where
+        Self: 'a,
+    {
+        FutureTransaction::new(ready(self.execute("COMMIT")))
+    }
+
+    fn rollback<'a>(self) -> FutureTransaction<'a, ()>
+    where
+        Self: 'a,
+    {
+        FutureTransaction::new(ready(self.execute("ROLLBACK")))
+    }
+
+    fn savepoint(&mut self, name: &str) -> FutureTransaction<'_, ()> {
+        let sql = sql::savepoint(name);
+
+        FutureTransaction::new(ready(self.execute(&sql)))
+    }
+
+    fn rollback_to(&mut self, name: &str) -> FutureTransaction<'_, ()> {
+        let sql = sql::rollback_to(name);
+
+        FutureTransaction::new(ready(self.execute(&sql)))
+    }
+
+    fn release(&mut self, name: &str) -> FutureTransaction<'_, ()> {
+        let sql = sql::release(name);
+
+        FutureTransaction::new(ready(self.execute(&sql)))
+    }
+}
+
+impl<'a, T> Select<'a, T> for SqliteTransaction<T>
+where
+    T: Table + 'a,
+{
+    type Output = Ready<Result<Records<'a, T>, select::Error>>;
+
+    fn execute(&'a self, options: Options) -> Self::Output {
+        let sql = sql::select(T::NAME, &columns::<T>(), None) + &sql::options(&options);
+
+        ready(
+            records(&self.conn, &sql, Vec::new())
+                .map(|records| Records::from_stream(iter(records))),
+        )
+    }
+}
+
+impl<'a, T, P> Filter<'a, T, P> for SqliteTransaction<T>
+where
+    T: Table + 'a,
+    P: Predicate<T> + Condition,
+{
+    type Output = Ready<Result<Records<'a, T>, filter::Error>>;
+
+    fn execute(&'a self, predicate: P, options: Options) -> Self::Output {
+        let (condition, params) = condition(&predicate, 1);
+        let sql =
+            sql::select(T::NAME, &columns::<T>(), condition.as_deref()) + &sql::options(&options);
+
+        ready(records(&self.conn, &sql, params).map(|records| Records::from_stream(iter(records))))
+    }
+}
+
+impl<'a, T> Get<'a, T> for SqliteTransaction<T>
+where
+    T: Table + Keyed,
+    T::Key: Into<Value>,
+{
+    type Output = Ready<Result<Option<Record<T>>, get::Error>>;
+
+    fn execute(&'a self, key: T::Key) -> Self::Output {
+        let condition = format!("{} = ?1", sql::ident(T::KEY));
+        let sql = sql::select(T::NAME, &columns::<T>(), Some(&condition)) + " LIMIT 1";
+        let params = vec![key.into()];
+
+        ready(records(&self.conn, &sql, params).map(|records| records.into_iter().next()))
+    }
+}
+
+impl<'a, T> Insert<'a, T> for SqliteTransaction<T>
+where
+    T: Table + 'a,
+{
+    type Output = Ready<Result<Record<T>, insert::Error>>;
+
+    fn execute(&'a self, item: T) -> Self::Output {
+        let sql = sql::insert(T::NAME, T::COLUMNS);
+
+        ready(run(&self.conn, &sql, values(&item)).map(|_| Record::new(item)))
+    }
+}
+
+impl<'a, T> Upsert<'a, T> for SqliteTransaction<T>
+where
+    T: Table + Keyed + 'a,
+{
+    type Output = Ready<Result<Record<T>, upsert::Error>>;
+
+    fn execute(&'a self, item: T) -> Self::Output {
+        let sql = sql::upsert(T::NAME, T::COLUMNS);
+
+        ready(run(&self.conn, &sql, values(&item)).map(|_| Record::new(item)))
+    }
+}
+
+impl<'a, T, P, M> Update<'a, T, P, M> for SqliteTransaction<T>
+where
+    T: Table + Keyed + 'a,
+    T::Key: Into<Value>,
+    P: Predicate<T> + Condition,
+    M: Modify<T> + 'a,
+{
+    type Output = Ready<Result<Records<'a, T>, update::Error>>;
+
+    fn execute(&'a self, predicate: P, modify: M) -> Self::Output {
+        let (condition, params) = condition(&predicate, 1);
+        let sql = sql::select(T::NAME, &columns::<T>(), condition.as_deref());
+
+        ready(records(&self.conn, &sql, params).and_then(|mut records| {
+            apply(&self.conn, &mut records, &modify)?;
+
+            Ok(Records::from_stream(iter(records)))
+        }))
+    }
+}
+
+impl<'a, T, P> Delete<'a, T, P> for SqliteTransaction<T>
+where
+    T: Table,
+    P: Predicate<T> + Condition,
+{
+    type Output = Ready<Result<usize, delete::Error>>;
+
+    fn execute(&'a self, predicate: P) -> Self::Output {
+        let (condition, params) = condition(&predicate, 1);
+        let sql = sql::delete(T::NAME, condition.as_deref());
+
+        ready(run(&self.conn, &sql, bind(&params)))
+    }
+}
+
+impl<'a, T, P> Count<'a, T, P> for SqliteTransaction<T>
+where
+    T: Table,
+    P: Predicate<T> + Condition,
+{
+    type Output = Ready<Result<usize, count::Error>>;
+
+    fn execute(&'a self, predicate: Option<P>) -> Self::Output {
+        let (condition, params) = optional(predicate.as_ref());
+        let sql = sql::count(T::NAME, condition.as_deref());
+
+        ready(scalar(&self.conn, &sql, params).map(|count| count as usize))
+    }
+}
+
+impl<'a, T, P> Exists<'a, T, P> for SqliteTransaction<T>
+where
+    T: Table,
+    P: Predicate<T> + Condition,
+{
+    type Output = Ready<Result<bool, exists::Error>>;
+
+    fn execute(&'a self, predicate: Option<P>) -> Self::Output {
+        let (condition, params) = optional(predicate.as_ref());
+        let sql = sql::exists(T::NAME, condition.as_deref());
+
+        ready(scalar(&self.conn, &sql, params).map(|exists| exists != 0))
+    }
+}
+
+impl<'a, T> Paginate<'a, T> for SqliteTransaction<T>
+where
+    T: Table + Keyed + 'a,
+    T::Key: Into<Value>,
+{
+    type Output = Ready<Result<Page<'a, T>, page::Error>>;
+
+    fn execute(&'a self, options: Options, cursor: Option<Cursor>) -> Self::Output {
+        ready(paginate(&self.conn, options, cursor))
+    }
+}
+
+impl<'a, T, P> Project<'a, T, P> for SqliteTransaction<T>
+where
+    T: Table,
+    P: Predicate<T> + Condition,
+{
+    type Output = Ready<Result<Records<'a, Row>, project::Error>>;
+
+    fn execute(
+        &'a self,
+        projection: Projection,
+        predicate: Option<P>,
+        options: Options,
+    ) -> Self::Output {
+        let (condition, params) = optional(predicate.as_ref());
+        let sql = sql::select(T::NAME, &sql::columns(&projection), condition.as_deref())
+            + &sql::options(&options);
+
+        ready(
+            query(&self.conn, &sql, params)
+                .map(|rows| Records::from_stream(iter(rows.into_iter().map(Record::new)))),
+        )
+    }
+}
+
+impl<'a, T, P> With<'a, T, P> for SqliteTransaction<T>
+where
+    T: Table + Related + 'a,
+    P: Predicate<T> + Condition,
+{
+    type Output = Ready<Result<Records<'a, T>, with::Error>>;
+
+    fn execute(
+        &'a self,
+        relations: Vec<String>,
+        predicate: Option<P>,
+        options: Options,
+    ) -> Self::Output {
+        let (condition, params) = optional(predicate.as_ref());
+        let sql =
+            sql::select(T::NAME, &columns::<T>(), condition.as_deref()) + &sql::options(&options);
+
+        ready(
+            eager(&self.conn, &sql, params, &relations)
+                .map(|records| Records::from_stream(iter(records))),
+        )
+    }
+}
@@ -0,0 +1,52 @@
+use rusqlite::types::{Value as SqlValue, ValueRef};
+
+use brace_data_store::{Error, Value};
+
+pub fn to_sql(value: Option<&Value>) -> SqlValue {
+    match value {
+        Some(Value::Bool(value)) => SqlValue::Integer(*value as i64),
+        Some(Value::Integer(value)) => SqlValue::Integer(*value),
+        Some(Value::Float(value)) => SqlValue::Real(*value),
+        Some(Value::Text(value)) => SqlValue::Text(value.clone()),
+        None => SqlValue::Null,
+    }
+}
+
+pub fn from_sql(value: ValueRef<'_>) -> Result<Option<Value>, Error> {
+    match value {
+        ValueRef::Null => Ok(None),
+        ValueRef::Integer(value) => Ok(Some(Value::Integer(value))),
+        ValueRef::Real(value) => Ok(Some(Value::Float(value))),
+        ValueRef::Text(value) => match std::str::from_utf8(value) {
+            Ok(value) => Ok(Some(Value::Text(value.to_owned()))),
+            Err(_) => Err(Error::serialization("Text value is not valid UTF-8")),
+        },
+        ValueRef::Blob(_) => Err(Error::serialization("Blob values are not supported")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rusqlite::types::ValueRef;
+
+    use brace_data_store::{ErrorKind, Value};
+
+    use super::from_sql;
+
+    #[test]
+    fn test_value_from_sql() {
+        assert_eq!(from_sql(ValueRef::Null).unwrap(), None);
+        assert_eq!(
+            from_sql(ValueRef::Text(b"abc")).unwrap(),
+            Some(Value::from("abc"))
+        );
+        assert_eq!(
+            from_sql(ValueRef::Blob(b"abc")).unwrap_err().kind(),
+            ErrorKind::Serialization
+        );
+        assert_eq!(
+            from_sql(ValueRef::Text(&[0xff])).unwrap_err().kind(),
+            ErrorKind::Serialization
+        );
+    }
+}
@@ -0,0 +1,330 @@
+use futures::stream::StreamExt;
+
+use brace_data_store::{
+    field, Error, ErrorKind, Fields, Isolation, Keyed, Options, Order, Projection, Related,
+    Relation, Row, Store, Transaction, Value,
+};
+use brace_data_store_sqlite::{Sqlite, SqliteStore, Table};
+
+#[derive(Clone, Debug, PartialEq)]
+struct User {
+    name: String,
+    age: i64,
+}
+
+impl User {
+    fn new(name: &str, age: i64) -> Self {
+        Self {
+            name: name.to_owned(),
+            age,
+        }
+    }
+}
+
+impl Keyed for User {
+    type Key = String;
+
+    fn key(&self) -> Self::Key {
+        self.name.clone()
+    }
+}
+
+impl Fields for User {
+    fn field(&self, name: &str) -> Option<Value> {
+        match name {
+            "name" => Some(Value::from(self.name.clone())),
+            "age" => Some(Value::from(self.age)),
+            _ => None,
+        }
+    }
+}
+
+impl Related for User {
+    fn relations() -> Vec<Relation> {
+        vec![Relation::has_many("posts", "posts", "author").with_local("name")]
+    }
+}
+
+impl Table for User {
+    const NAME: &'static str = "users";
+    const KEY: &'static str = "name";
+    const COLUMNS: &'static [&'static str] = &["name", "age"];
+
+    fn from_row(row: Row) -> Result<Self, Error> {
+        match (row.get("name"), row.get("age")) {
+            (Some(Value::Text(name)), Some(Value::Integer(age))) => Ok(Self::new(name, *age)),
+            _ => Err(Error::serialization("Invalid user row")),
+        }
+    }
+}
+
+async fn users() -> Result<SqliteStore<User>, Error> {
+    let sqlite = Sqlite::memory();
+    let conn = sqlite.connect().await?;
+
+    conn.execute_batch(
+        "CREATE TABLE users (name TEXT PRIMARY KEY, age INTEGER NOT NULL); \
+         CREATE TABLE posts (author TEXT NOT NULL, title TEXT NOT NULL); \
+         INSERT INTO posts VALUES ('alice', 'First'), ('alice', 'Second'), ('carol', 'Third')",
+    )
+    .map_err(brace_data_store_sqlite::Error::from)?;
+
+    drop(conn);
+
+    let store = sqlite.store();
+
+    store.insert(User::new("alice", 31)).await?;
+    store.insert(User::new("bob", 27)).await?;
+    store.insert(User::new("carol", 45)).await?;
+
+    Ok(store)
+}
+
+fn names(users: Vec<User>) -> Vec<String> {
+    users.into_iter().map(|user| user.name).collect()
+}
+
+#[tokio::test]
+async fn test_sqlite_insert_get() -> Result<(), Error> {
+    let store = users().await?;
+
+    assert_eq!(
+        store
+            .get("bob".to_owned())
+            .await?
+            .map(|record| record.into_inner()),
+        Some(User::new("bob", 27))
+    );
+    assert!(store.get("dave".to_owned()).await?.is_none());
+
+    let error = store.insert(User::new("bob", 99)).await.unwrap_err();
+
+    assert_eq!(error.kind(), ErrorKind::ConstraintViolation);
+
+    store.upsert(User::new("bob", 99)).await?;
+
+    assert_eq!(
+        store.get("bob".to_owned()).await?.map(|record| record.age),
+        Some(99)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sqlite_select_filter() -> Result<(), Error> {
+    let store = users().await?;
+    let options = Options::new().order_by(Order::desc("age")).with_limit(2);
+    let records = store.select_with(options).await?;
+    let users = records.map(|record| record.into_inner()).collect();
+
+    assert_eq!(names(users.await), vec!["carol", "alice"]);
+
+    let options = Options::new().order_by(Order::asc("name"));
+    let records = store.filter_with(field("age").gt(30), options).await?;
+    let users = records.map(|record| record.into_inner()).collect();
+
+    assert_eq!(names(users.await), vec!["alice", "carol"]);
+    assert_eq!(store.count().await?, 3);
+    assert_eq!(store.count_where(field("age").lt(30)).await?, 1);
+    assert!(!store.exists_where(field("age").gt(50)).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sqlite_update_delete() -> Result<(), Error> {
+    let store = users().await?;
+    let records = store
+        .update(field("name").eq("bob"), |user: &mut User| user.age += 1)
+        .await?;
+    let updated = records.collect::<Vec<_>>().await;
+
+    assert_eq!(updated.len(), 1);
+    assert_eq!(updated[0].age, 28);
+    assert_eq!(
+        store.get("bob".to_owned()).await?.map(|record| record.age),
+        Some(28)
+    );
+
+    assert_eq!(store.delete(field("age").gt(30)).await?, 2);
+    assert_eq!(store.count().await?, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sqlite_page_project() -> Result<(), Error> {
+    let store = users().await?;
+    let options = Options::new().order_by(Order::asc("age")).with_limit(2);
+    let (records, cursor) = store.page(options.clone(), None).await?.into_parts();
+    let users = records.map(|record| record.into_inner()).collect();
+
+    assert_eq!(names(users.await), vec!["bob", "alice"]);
+
+    let (records, cursor) = store.page(options, cursor).await?.into_parts();
+    let users = records.map(|record| record.into_inner()).collect();
+
+    assert_eq!(names(users.await), vec!["carol"]);
+    assert!(cursor.is_none());
+
+    let rows = store
+        .project_with(
+            Projection::new(vec!["name"]),
+            field("age").lt(30),
+            Options::new(),
+        )
+        .await?;
+    let rows = rows.map(|record| record.into_inner()).collect::<Vec<_>>();
+
+    assert_eq!(
+        rows.await,
+        vec![vec![("name", Value::from("bob"))]
+            .into_iter()
+            .collect::<Row>()]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sqlite_page_ties() -> Result<(), Error> {
+    let store = users().await?;
+
+    store.insert(User::new("dave", 27)).await?;
+
+    let options = Options::new().order_by(Order::asc("age")).with_limit(1);
+    let (_, cursor) = store.page(options.clone(), None).await?.into_parts();
+    let (records, _) = store.page(options, cursor).await?.into_parts();
+    let users = records.map(|record| record.into_inner()).collect();
+
+    assert_eq!(names(users.await), vec!["dave"]);
+
+    let options = Options::new().with_limit(3);
+    let (_, cursor) = store.page(options.clone(), None).await?.into_parts();
+    let (records, _) = store.page(options, cursor).await?.into_parts();
+    let users = records.map(|record| record.into_inner()).collect();
+
+    assert_eq!(names(users.await), vec!["dave"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sqlite_with() -> Result<(), Error> {
+    let store = users().await?;
+    let options = Options::new().order_by(Order::asc("name"));
+    let records = store
+        .with_where(vec!["posts"], field("age").gt(0), options)
+        .await?
+        .collect::<Vec<_>>()
+        .await;
+    let titles = records
+        .iter()
+        .map(|record| {
+            record
+                .related("posts")
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|row| row.field("title"))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(records.len(), 3);
+    assert_eq!(titles[0], vec![Value::from("First"), Value::from("Second")]);
+    assert!(titles[1].is_empty());
+    assert_eq!(titles[2], vec![Value::from("Third")]);
+    assert!(store.with(vec!["missing"]).await.is_err());
+
+    let tx = store.begin(Isolation::ReadCommitted).await?;
+    let records = tx.with(vec!["posts"]).await?.collect::<Vec<_>>().await;
+
+    assert_eq!(records.len(), 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sqlite_transaction() -> Result<(), Error> {
+    let store = users().await?;
+    let mut tx = store.begin(Isolation::Serializable).await?;
+
+    tx.insert(User::new("dave", 19)).await?;
+    tx.savepoint("before_erin").await?;
+    tx.insert(User::new("erin", 52)).await?;
+    tx.rollback_to("before_erin").await?;
+    tx.update(field("name").eq("dave"), |user: &mut User| user.age += 1)
+        .await?;
+
+    assert_eq!(tx.count().await?, 4);
+    assert_eq!(
+        tx.get("dave".to_owned()).await?.map(|user| user.age),
+        Some(20)
+    );
+
+    tx.commit().await?;
+
+    assert_eq!(store.count().await?, 4);
+
+    let tx = store.begin(Isolation::ReadCommitted).await?;
+
+    tx.delete(field("age").gt(0)).await?;
+    tx.rollback().await?;
+
+    assert_eq!(store.count().await?, 4);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sqlite_transaction_drop() -> Result<(), Error> {
+    let store = users().await?;
+
+    {
+        let tx = store.begin(Isolation::ReadCommitted).await?;
+
+        tx.insert(User::new("frank", 23)).await?;
+
+        assert!(tx.get("frank".to_owned()).await?.is_some());
+    }
+
+    assert!(store.get("frank".to_owned()).await?.is_none());
+    assert_eq!(store.count().await?, 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sqlite_blocking() -> Result<(), Error> {
+    let store = users().await?;
+    let insert = store.insert(User::new("dave", 19));
+
+    assert_eq!(store.count().await?, 4);
+
+    insert.await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sqlite_memory_shared() -> Result<(), Error> {
+    let store = users().await?;
+    let other = users().await?;
+    let tx = store.begin(Isolation::ReadCommitted).await?;
+
+    assert_eq!(store.count().await?, 3);
+
+    tx.insert(User::new("dave", 19)).await?;
+
+    let error = store.insert(User::new("erin", 52)).await.unwrap_err();
+
+    assert_eq!(error.kind(), ErrorKind::Conflict);
+
+    tx.commit().await?;
+
+    assert_eq!(store.count().await?, 4);
+    assert_eq!(other.count().await?, 3);
+
+    Ok(())
+}
@@ -0,0 +1,185 @@
+use std::error;
+use std::fmt::{self, Display};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    NotFound,
+    Conflict,
+    ConstraintViolation,
+    Connection,
+    Timeout,
+    Serialization,
+    Backend,
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NotFound => write!(f, "not found"),
+            Self::Conflict => write!(f, "conflict"),
+            Self::ConstraintViolation => write!(f, "constraint violation"),
+            Self::Connection => write!(f, "connection error"),
+            Self::Timeout => write!(f, "timed out"),
+            Self::Serialization => write!(f, "serialization error"),
+            Self::Backend => write!(f, "backend error"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Error {
+    kind: ErrorKind,
+    message: Option<String>,
+    source: Option<Box<dyn error::Error + Send + Sync>>,
+}
+
+impl Error {
+    pub fn new<T>(kind: ErrorKind, message: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            kind,
+            message: Some(message.into()),
+            source: None,
+        }
+    }
+
+    pub fn from_source<E>(kind: ErrorKind, source: E) -> Self
+    where
+        E: Into<Box<dyn error::Error + Send + Sync>>,
+    {
+        Self {
+            kind,
+            message: None,
+            source: Some(source.into()),
+        }
+    }
+
+    pub fn with_source<E>(mut self, source: E) -> Self
+    where
+        E: Into<Box<dyn error::Error + Send + Sync>>,
+    {
+        self.source = Some(source.into());
+        self
+    }
+
+    pub fn message<T>(message: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::new(ErrorKind::Backend, message)
+    }
+
+    pub fn not_found<T>(message: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::new(ErrorKind::NotFound, message)
+    }
+
+    pub fn conflict<T>(message: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::new(ErrorKind::Conflict, message)
+    }
+
+    pub fn constraint_violation<T>(message: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::new(ErrorKind::ConstraintViolation, message)
+    }
+
+    pub fn connection<T>(message: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::new(ErrorKind::Connection, message)
+    }
+
+    pub fn timeout<T>(message: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::new(ErrorKind::Timeout, message)
+    }
+
+    pub fn serialization<T>(message: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::new(ErrorKind::Serialization, message)
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    pub fn is(&self, kind: ErrorKind) -> bool {
+        self.kind == kind
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (&self.message, &self.source) {
+            (Some(message), _) => message.fmt(f),
+            (None, Some(source)) => source.fmt(f),
+            (None, None) => self.kind.fmt(f),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match &self.source {
+            Some(source) => Some(source.as_ref()),
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error as _;
+    use std::fmt;
+
+    use super::{Error, ErrorKind};
+
+    #[derive(Debug)]
+    struct Driver;
+
+    impl fmt::Display for Driver {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "duplicate key")
+        }
+    }
+
+    impl std::error::Error for Driver {}
+
+    #[test]
+    fn test_error_kind() {
+        let error = Error::not_found("Book does not exist");
+
+        assert_eq!(error.kind(), ErrorKind::NotFound);
+        assert!(error.is(ErrorKind::NotFound));
+        assert_eq!(error.to_string(), "Book does not exist");
+        assert!(error.source().is_none());
+        assert_eq!(Error::message("Failed").kind(), ErrorKind::Backend);
+    }
+
+    #[test]
+    fn test_error_source() {
+        let error = Error::from_source(ErrorKind::ConstraintViolation, Driver);
+
+        assert_eq!(error.to_string(), "duplicate key");
+        assert_eq!(error.source().unwrap().to_string(), "duplicate key");
+
+        let error = Error::conflict("Book already exists").with_source(Driver);
+
+        assert_eq!(error.to_string(), "Book already exists");
+        assert!(error.source().unwrap().is::<Driver>());
+    }
+}
@@ -0,0 +1,144 @@
+use std::cmp::Ordering;
+
+use crate::record::Record;
+
+pub trait Fields {
+    fn field(&self, name: &str) -> Option<Value>;
+}
+
+impl<T> Fields for Record<T>
+where
+    T: Fields,
+{
+    fn field(&self, name: &str) -> Option<Value> {
+        (**self).field(name)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Value {
+    Bool(bool),
+    Integer(i64),
+    Float(f64),
+    Text(String),
+}
+
+impl Value {
+    fn rank(&self) -> u8 {
+        match self {
+            Self::Bool(_) => 0,
+            Self::Integer(_) | Self::Float(_) => 1,
+            Self::Text(_) => 2,
+        }
+    }
+}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Value {}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Value {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Bool(a), Self::Bool(b)) => a.cmp(b),
+            (Self::Integer(a), Self::Integer(b)) => a.cmp(b),
+            (Self::Integer(a), Self::Float(b)) => mixed(*a, *b),
+            (Self::Float(a), Self::Integer(b)) => mixed(*b, *a).reverse(),
+            (Self::Float(a), Self::Float(b)) => a.total_cmp(b),
+            (Self::Text(a), Self::Text(b)) => a.cmp(b),
+            (a, b) => a.rank().cmp(&b.rank()),
+        }
+    }
+}
+
+fn mixed(integer: i64, float: f64) -> Ordering {
+    match (integer as f64).total_cmp(&float) {
+        Ordering::Equal if float >= i64::MAX as f64 => Ordering::Less,
+        Ordering::Equal => integer.cmp(&(float as i64)),
+        ordering => ordering,
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<i32> for Value {
+    fn from(value: i32) -> Self {
+        Self::Integer(value.into())
+    }
+}
+
+impl From<i64> for Value {
+    fn from(value: i64) -> Self {
+        Self::Integer(value)
+    }
+}
+
+impl From<u32> for Value {
+    fn from(value: u32) -> Self {
+        Self::Integer(value.into())
+    }
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_owned())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Value;
+
+    #[test]
+    fn test_value_ordering() {
+        assert!(Value::from(1) < Value::from(2));
+        assert!(Value::from(1) < Value::from(1.5));
+        assert!(Value::from(2.5) > Value::from(2));
+        assert!(Value::from("a") < Value::from("b"));
+        assert!(Value::from(false) < Value::from(true));
+        assert!(Value::from(true) < Value::from(0));
+        assert!(Value::from(10) < Value::from("1"));
+    }
+
+    #[test]
+    fn test_value_total_order() {
+        let nan = Value::from(f64::NAN);
+
+        assert_eq!(nan, Value::from(f64::NAN));
+        assert!(nan > Value::from(f64::INFINITY));
+        assert!(nan > Value::from(i64::MAX));
+        assert!(Value::from(-f64::NAN) < Value::from(i64::MIN));
+
+        assert_eq!(Value::from(1), Value::from(1.0));
+        assert_eq!(Value::from(1.0), Value::from(1));
+        assert!(Value::from(i64::MAX) < Value::from(i64::MAX as f64));
+        assert!(Value::from(i64::MAX - 1) < Value::from(i64::MAX));
+        assert_ne!(Value::from(1), Value::from(true));
+    }
+}
@@ -0,0 +1,18 @@
+use crate::record::Record;
+
+pub trait Keyed {
+    type Key;
+
+    fn key(&self) -> Self::Key;
+}
+
+impl<T> Keyed for Record<T>
+where
+    T: Keyed,
+{
+    type Key = T::Key;
+
+    fn key(&self) -> Self::Key {
+        (**self).key()
+    }
+}
@@ -1,46 +1,358 @@
-pub use self::query::filter::{Filter, FutureFilter, Predicate};
+use futures::stream::{iter, Iter, Stream};
+
+pub use self::error::{Error, ErrorKind};
+pub use self::field::{Fields, Value};
+pub use self::key::Keyed;
+pub use self::query::aggregate::{Aggregate, Aggregation, Function, FutureAggregate};
+pub use self::query::batch::{Batch, DeleteMany, FutureBatch, InsertMany};
+#[cfg(feature = "data")]
+pub use self::query::constraint::{invalid, valid, Invalid, Valid};
+pub use self::query::count::{Count, FutureCount};
+pub use self::query::delete::{Delete, FutureDelete};
+pub use self::query::exists::{Exists, FutureExists};
+pub use self::query::expr::{field, Expr, Field};
+pub use self::query::filter::{Filter, FutureFilter};
+pub use self::query::get::{FutureGet, Get};
+pub use self::query::insert::{FutureInsert, Insert};
+pub use self::query::options::{Direction, Nulls, Options, Order};
+pub use self::query::page::{paginate, Cursor, FuturePage, Page, Paginate};
+pub use self::query::predicate::{always, never, Always, Never, Predicate, PredicateExt, Shared};
+pub use self::query::project::{FutureProject, Project, Projection, Row};
+pub use self::query::save::{FutureSave, Save};
 pub use self::query::select::{FutureSelect, Select};
-pub use self::record::{Record, Records};
+pub use self::query::update::{FutureUpdate, Modify, Update};
+pub use self::query::upsert::{FutureUpsert, Upsert};
+pub use self::query::with::{FutureWith, With};
+pub use self::record::{Meta, Record, Records};
+pub use self::relation::{relate, resolve, Pivot, Related, Relation, Tables};
+pub use self::transaction::{Begin, FutureTransaction, Isolation, Transaction};
 
 pub mod connection;
+pub mod error;
+pub mod field;
+pub mod key;
 pub mod query;
 pub mod record;
+pub mod relation;
+pub mod transaction;
 
 pub trait Store {
     type Item;
 
+    fn begin<'a>(
+        &'a self,
+        isolation: Isolation,
+    ) -> FutureTransaction<'a, <Self as Begin<'a>>::Transaction>
+    where
+        Self: Begin<'a>,
+    {
+        FutureTransaction::new(Begin::execute(self, isolation))
+    }
+
     fn select<'a>(&'a self) -> FutureSelect<'a, Self::Item>
     where
         Self: Select<'a, <Self as Store>::Item>,
     {
-        FutureSelect::new(Select::execute(self))
+        self.select_with(Options::default())
+    }
+
+    fn select_with<'a>(&'a self, options: Options) -> FutureSelect<'a, Self::Item>
+    where
+        Self: Select<'a, <Self as Store>::Item>,
+    {
+        FutureSelect::new(Select::execute(self, options))
     }
 
     fn filter<'a, P>(&'a self, predicate: P) -> FutureFilter<'a, Self::Item, P>
     where
-        P: Predicate<Self::Item> + Copy,
+        P: Predicate<Self::Item>,
+        Self: Filter<'a, <Self as Store>::Item, P>,
+    {
+        self.filter_with(predicate, Options::default())
+    }
+
+    fn filter_with<'a, P>(
+        &'a self,
+        predicate: P,
+        options: Options,
+    ) -> FutureFilter<'a, Self::Item, P>
+    where
+        P: Predicate<Self::Item>,
         Self: Filter<'a, <Self as Store>::Item, P>,
     {
-        FutureFilter::new(Filter::execute(self, predicate))
+        FutureFilter::new(Filter::execute(self, predicate, options))
+    }
+
+    fn count<'a>(&'a self) -> FutureCount<'a, Self::Item, Always>
+    where
+        Self: Count<'a, <Self as Store>::Item, Always>,
+    {
+        FutureCount::new(Count::execute(self, None))
+    }
+
+    fn count_where<'a, P>(&'a self, predicate: P) -> FutureCount<'a, Self::Item, P>
+    where
+        P: Predicate<Self::Item>,
+        Self: Count<'a, <Self as Store>::Item, P>,
+    {
+        FutureCount::new(Count::execute(self, Some(predicate)))
+    }
+
+    fn exists<'a>(&'a self) -> FutureExists<'a, Self::Item, Always>
+    where
+        Self: Exists<'a, <Self as Store>::Item, Always>,
+    {
+        FutureExists::new(Exists::execute(self, None))
+    }
+
+    fn exists_where<'a, P>(&'a self, predicate: P) -> FutureExists<'a, Self::Item, P>
+    where
+        P: Predicate<Self::Item>,
+        Self: Exists<'a, <Self as Store>::Item, P>,
+    {
+        FutureExists::new(Exists::execute(self, Some(predicate)))
+    }
+
+    fn page<'a>(&'a self, options: Options, cursor: Option<Cursor>) -> FuturePage<'a, Self::Item>
+    where
+        Self::Item: Keyed,
+        Self: Paginate<'a, <Self as Store>::Item>,
+    {
+        FuturePage::new(Paginate::execute(self, options, cursor))
+    }
+
+    fn project<'a>(&'a self, projection: Projection) -> FutureProject<'a, Self::Item, Always>
+    where
+        Self: Project<'a, <Self as Store>::Item, Always>,
+    {
+        FutureProject::new(Project::execute(self, projection, None, Options::default()))
+    }
+
+    fn project_with<'a, P>(
+        &'a self,
+        projection: Projection,
+        predicate: P,
+        options: Options,
+    ) -> FutureProject<'a, Self::Item, P>
+    where
+        P: Predicate<Self::Item>,
+        Self: Project<'a, <Self as Store>::Item, P>,
+    {
+        FutureProject::new(Project::execute(self, projection, Some(predicate), options))
+    }
+
+    fn aggregate<'a>(&'a self, aggregation: Aggregation) -> FutureAggregate<'a, Self::Item, Always>
+    where
+        Self: Aggregate<'a, <Self as Store>::Item, Always>,
+    {
+        FutureAggregate::new(Aggregate::execute(
+            self,
+            aggregation,
+            None,
+            Options::default(),
+        ))
+    }
+
+    fn aggregate_with<'a, P>(
+        &'a self,
+        aggregation: Aggregation,
+        predicate: P,
+        options: Options,
+    ) -> FutureAggregate<'a, Self::Item, P>
+    where
+        P: Predicate<Self::Item>,
+        Self: Aggregate<'a, <Self as Store>::Item, P>,
+    {
+        FutureAggregate::new(Aggregate::execute(
+            self,
+            aggregation,
+            Some(predicate),
+            options,
+        ))
+    }
+
+    fn with<'a, I>(&'a self, relations: I) -> FutureWith<'a, Self::Item, Always>
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+        Self: With<'a, <Self as Store>::Item, Always>,
+    {
+        FutureWith::new(With::execute(
+            self,
+            relations.into_iter().map(Into::into).collect(),
+            None,
+            Options::default(),
+        ))
+    }
+
+    fn with_where<'a, I, P>(
+        &'a self,
+        relations: I,
+        predicate: P,
+        options: Options,
+    ) -> FutureWith<'a, Self::Item, P>
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+        P: Predicate<Self::Item>,
+        Self: With<'a, <Self as Store>::Item, P>,
+    {
+        FutureWith::new(With::execute(
+            self,
+            relations.into_iter().map(Into::into).collect(),
+            Some(predicate),
+            options,
+        ))
+    }
+
+    fn get<'a>(&'a self, key: <Self::Item as Keyed>::Key) -> FutureGet<'a, Self::Item>
+    where
+        Self::Item: Keyed,
+        Self: Get<'a, <Self as Store>::Item>,
+    {
+        FutureGet::new(Get::execute(self, key))
+    }
+
+    fn insert<'a>(&'a self, item: Self::Item) -> FutureInsert<'a, Self::Item>
+    where
+        Self: Insert<'a, <Self as Store>::Item>,
+    {
+        FutureInsert::new(Insert::execute(self, item))
+    }
+
+    fn update<'a, P, M>(&'a self, predicate: P, modify: M) -> FutureUpdate<'a, Self::Item, P, M>
+    where
+        P: Predicate<Self::Item>,
+        M: Modify<Self::Item>,
+        Self: Update<'a, <Self as Store>::Item, P, M>,
+    {
+        FutureUpdate::new(Update::execute(self, predicate, modify))
+    }
+
+    fn save<'a>(&'a self, record: Record<Self::Item>) -> FutureSave<'a, Self::Item>
+    where
+        Self: Save<'a, <Self as Store>::Item>,
+    {
+        FutureSave::new(Save::execute(self, record))
+    }
+
+    fn delete<'a, P>(&'a self, predicate: P) -> FutureDelete<'a, Self::Item, P>
+    where
+        P: Predicate<Self::Item>,
+        Self: Delete<'a, <Self as Store>::Item, P>,
+    {
+        FutureDelete::new(Delete::execute(self, predicate))
+    }
+
+    fn upsert<'a>(&'a self, item: Self::Item) -> FutureUpsert<'a, Self::Item>
+    where
+        Self::Item: Keyed,
+        Self: Upsert<'a, <Self as Store>::Item>,
+    {
+        FutureUpsert::new(Upsert::execute(self, item))
+    }
+
+    fn insert_many<'a, S>(&'a self, items: S) -> FutureBatch<'a, Self::Item>
+    where
+        S: Stream<Item = Self::Item> + 'a,
+        Self: InsertMany<'a, <Self as Store>::Item, S>,
+    {
+        FutureBatch::new(InsertMany::execute(self, items))
+    }
+
+    fn insert_iter<'a, I>(&'a self, items: I) -> FutureBatch<'a, Self::Item>
+    where
+        I: IntoIterator<Item = Self::Item>,
+        I::IntoIter: 'a,
+        Self: InsertMany<'a, <Self as Store>::Item, Iter<I::IntoIter>>,
+    {
+        FutureBatch::new(InsertMany::execute(self, iter(items)))
+    }
+
+    fn delete_many<'a, S>(&'a self, keys: S) -> FutureBatch<'a, Self::Item>
+    where
+        S: Stream<Item = <Self::Item as Keyed>::Key> + 'a,
+        Self::Item: Keyed,
+        Self: DeleteMany<'a, <Self as Store>::Item, S>,
+    {
+        FutureBatch::new(DeleteMany::execute(self, keys))
+    }
+
+    fn delete_iter<'a, I>(&'a self, keys: I) -> FutureBatch<'a, Self::Item>
+    where
+        I: IntoIterator<Item = <Self::Item as Keyed>::Key>,
+        I::IntoIter: 'a,
+        Self::Item: Keyed,
+        Self: DeleteMany<'a, <Self as Store>::Item, Iter<I::IntoIter>>,
+    {
+        FutureBatch::new(DeleteMany::execute(self, iter(keys)))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use futures::future::{ok, Ready};
-    use futures::stream::{iter, StreamExt};
+    use std::borrow::Borrow;
+    use std::cell::RefCell;
+
+    use futures::future::{err, ok, Ready};
+    use futures::stream::{iter, Stream, StreamExt};
     use indexmap::IndexSet;
 
     use brace_util_future::result::FutureResult;
 
+    use crate::query::aggregate::{self, Aggregate, Aggregation};
+    use crate::query::batch::{self, Batch, DeleteMany, InsertMany};
+    use crate::query::count::{self, Count};
+    use crate::query::delete::{self, Delete};
+    use crate::query::exists::{self, Exists};
+    use crate::query::expr::field;
     use crate::query::filter::{self, Filter, Predicate};
+    use crate::query::get::{self, Get};
+    use crate::query::insert::{self, Insert};
+    use crate::query::options::{Options, Order};
+    use crate::query::page::{self, Cursor, Page, Paginate};
+    use crate::query::predicate::{never, PredicateExt};
+    use crate::query::project::{self, Project, Projection, Row};
     use crate::query::select::{self, Select};
-    use crate::{Record, Records, Store};
+    use crate::query::update::{self, Modify, Update};
+    use crate::query::upsert::{self, Upsert};
+    use crate::transaction::{self, Begin, FutureTransaction, Isolation, Transaction};
+    use crate::{ErrorKind, Fields, Keyed, Record, Records, Store, Value};
 
     #[derive(Clone, Debug, PartialEq, Eq, Hash)]
     struct Book(&'static str);
 
-    struct Books(IndexSet<Book>);
+    impl Keyed for Book {
+        type Key = &'static str;
+
+        fn key(&self) -> Self::Key {
+            self.0
+        }
+    }
+
+    impl Fields for Book {
+        fn field(&self, name: &str) -> Option<Value> {
+            match name {
+                "title" => Some(self.0.into()),
+                "words" => Some(Value::from(self.0.split(' ').count() as i64)),
+                _ => None,
+            }
+        }
+    }
+
+    impl Borrow<str> for Book {
+        fn borrow(&self) -> &str {
+            self.0
+        }
+    }
+
+    struct Books(RefCell<IndexSet<Book>>);
+
+    impl Books {
+        fn items(&self) -> Vec<Book> {
+            self.0.borrow().iter().cloned().collect()
+        }
+    }
 
     impl Store for Books {
         type Item = Book;
@@ -49,10 +361,10 @@ mod tests {
     impl<'a> Select<'a, Book> for Books {
         type Output = FutureResult<'a, Records<'a, Book>, select::Error>;
 
-        fn execute(&'a self) -> Self::Output {
+        fn execute(&'a self, options: Options) -> Self::Output {
             FutureResult::from_future(async move {
                 Ok(Records::from_stream(iter(
-                    self.0.iter().map(|item| Record::new(item.clone())),
+                    options.apply(self.items()).into_iter().map(Record::new),
                 )))
             })
         }
@@ -60,22 +372,341 @@ mod tests {
 
     impl<'a, P> Filter<'a, Book, P> for Books
     where
-        P: Predicate<Book> + Copy + 'a,
+        P: Predicate<Book> + 'a,
     {
         type Output = Ready<Result<Records<'a, Book>, filter::Error>>;
 
+        fn execute(&'a self, predicate: P, options: Options) -> Self::Output {
+            let items = self
+                .items()
+                .into_iter()
+                .filter(|item| predicate.test(item))
+                .collect();
+
+            ok(Records::from_stream(iter(
+                options.apply(items).into_iter().map(Record::new),
+            )))
+        }
+    }
+
+    impl<'a, P> Count<'a, Book, P> for Books
+    where
+        P: Predicate<Book>,
+    {
+        type Output = Ready<Result<usize, count::Error>>;
+
+        fn execute(&'a self, predicate: Option<P>) -> Self::Output {
+            let books = self.0.borrow();
+
+            match predicate {
+                Some(predicate) => ok(books.iter().filter(|item| predicate.test(item)).count()),
+                None => ok(books.len()),
+            }
+        }
+    }
+
+    impl<'a, P> Exists<'a, Book, P> for Books
+    where
+        P: Predicate<Book>,
+    {
+        type Output = Ready<Result<bool, exists::Error>>;
+
+        fn execute(&'a self, predicate: Option<P>) -> Self::Output {
+            let books = self.0.borrow();
+
+            match predicate {
+                Some(predicate) => ok(books.iter().any(|item| predicate.test(item))),
+                None => ok(!books.is_empty()),
+            }
+        }
+    }
+
+    impl<'a> Paginate<'a, Book> for Books {
+        type Output = Ready<Result<Page<'a, Book>, page::Error>>;
+
+        fn execute(&'a self, options: Options, cursor: Option<Cursor>) -> Self::Output {
+            let (items, cursor) = page::paginate(self.items(), &options, cursor.as_ref());
+
+            ok(Page::new(
+                Records::from_stream(iter(items.into_iter().map(Record::new))),
+                cursor,
+            ))
+        }
+    }
+
+    impl<'a, P> Project<'a, Book, P> for Books
+    where
+        P: Predicate<Book>,
+    {
+        type Output = Ready<Result<Records<'a, Row>, project::Error>>;
+
+        fn execute(
+            &'a self,
+            projection: Projection,
+            predicate: Option<P>,
+            options: Options,
+        ) -> Self::Output {
+            let items = self
+                .items()
+                .into_iter()
+                .filter(|item| match &predicate {
+                    Some(predicate) => predicate.test(item),
+                    None => true,
+                })
+                .collect();
+
+            ok(Records::from_stream(iter(
+                options
+                    .apply(items)
+                    .into_iter()
+                    .map(move |item| Record::new(projection.apply(&item))),
+            )))
+        }
+    }
+
+    impl<'a, P> Aggregate<'a, Book, P> for Books
+    where
+        P: Predicate<Book>,
+    {
+        type Output = Ready<Result<Records<'a, Row>, aggregate::Error>>;
+
+        fn execute(
+            &'a self,
+            aggregation: Aggregation,
+            predicate: Option<P>,
+            options: Options,
+        ) -> Self::Output {
+            let items = self
+                .items()
+                .into_iter()
+                .filter(|item| match &predicate {
+                    Some(predicate) => predicate.test(item),
+                    None => true,
+                })
+                .collect::<Vec<_>>();
+
+            ok(Records::from_stream(iter(
+                options
+                    .apply(aggregation.apply(&items))
+                    .into_iter()
+                    .map(Record::new),
+            )))
+        }
+    }
+
+    impl<'a> Get<'a, Book> for Books {
+        type Output = Ready<Result<Option<Record<Book>>, get::Error>>;
+
+        fn execute(&'a self, key: &'static str) -> Self::Output {
+            ok(self.0.borrow().get(key).cloned().map(Record::new))
+        }
+    }
+
+    impl<'a> Insert<'a, Book> for Books {
+        type Output = Ready<Result<Record<Book>, insert::Error>>;
+
+        fn execute(&'a self, item: Book) -> Self::Output {
+            if !self.0.borrow_mut().insert(item.clone()) {
+                return err(insert::Error::conflict("Book already exists"));
+            }
+
+            ok(Record::new(item))
+        }
+    }
+
+    impl<'a, P, M> Update<'a, Book, P, M> for Books
+    where
+        P: Predicate<Book>,
+        M: Modify<Book>,
+    {
+        type Output = Ready<Result<Records<'a, Book>, update::Error>>;
+
+        fn execute(&'a self, predicate: P, modify: M) -> Self::Output {
+            let mut updated = Vec::new();
+            let mut books = self.0.borrow_mut();
+
+            *books = books
+                .drain(..)
+                .map(|mut item| {
+                    if predicate.test(&item) {
+                        modify.modify(&mut item);
+                        updated.push(Record::new(item.clone()));
+                    }
+
+                    item
+                })
+                .collect();
+
+            ok(Records::from_stream(iter(updated)))
+        }
+    }
+
+    impl<'a, P> Delete<'a, Book, P> for Books
+    where
+        P: Predicate<Book>,
+    {
+        type Output = Ready<Result<usize, delete::Error>>;
+
         fn execute(&'a self, predicate: P) -> Self::Output {
-            ok(Records::from_stream(
-                iter(self.0.iter().map(|item| Record::new(item.clone()))).filter_map(
-                    move |item| async move {
-                        if predicate.test(&item) {
-                            return Some(item);
+            let mut books = self.0.borrow_mut();
+            let len = books.len();
+
+            books.retain(|item| !predicate.test(item));
+
+            ok(len - books.len())
+        }
+    }
+
+    impl<'a> Upsert<'a, Book> for Books {
+        type Output = Ready<Result<Record<Book>, upsert::Error>>;
+
+        fn execute(&'a self, item: Book) -> Self::Output {
+            self.0.borrow_mut().replace(item.clone());
+
+            ok(Record::new(item))
+        }
+    }
+
+    impl<'a, S> InsertMany<'a, Book, S> for Books
+    where
+        S: Stream<Item = Book> + 'a,
+    {
+        type Output = FutureResult<'a, Batch<Book>, batch::Error>;
+
+        fn execute(&'a self, items: S) -> Self::Output {
+            FutureResult::from_future(async move {
+                Ok(items
+                    .map(|item| {
+                        if !self.0.borrow_mut().insert(item.clone()) {
+                            return Err(batch::Error::conflict("Book already exists"));
                         }
 
-                        None
-                    },
-                ),
-            ))
+                        Ok(Record::new(item))
+                    })
+                    .collect()
+                    .await)
+            })
+        }
+    }
+
+    impl<'a, S> DeleteMany<'a, Book, S> for Books
+    where
+        S: Stream<Item = &'static str> + 'a,
+    {
+        type Output = FutureResult<'a, Batch<Book>, batch::Error>;
+
+        fn execute(&'a self, keys: S) -> Self::Output {
+            FutureResult::from_future(async move {
+                Ok(keys
+                    .map(|key| match self.0.borrow_mut().shift_take(key) {
+                        Some(item) => Ok(Record::new(item)),
+                        None => Err(batch::Error::not_found("Book does not exist")),
+                    })
+                    .collect()
+                    .await)
+            })
+        }
+    }
+
+    impl<'a> Begin<'a> for Books {
+        type Transaction = BooksTransaction<'a>;
+        type Output = Ready<Result<BooksTransaction<'a>, transaction::Error>>;
+
+        fn execute(&'a self, _: Isolation) -> Self::Output {
+            ok(BooksTransaction {
+                store: self,
+                books: Books(RefCell::new(self.0.borrow().clone())),
+                savepoints: Vec::new(),
+            })
+        }
+    }
+
+    struct BooksTransaction<'a> {
+        store: &'a Books,
+        books: Books,
+        savepoints: Vec<(String, IndexSet<Book>)>,
+    }
+
+    impl Store for BooksTransaction<'_> {
+        type Item = Book;
+    }
+
+    impl<'a> Select<'a, Book> for BooksTransaction<'_> {
+        type Output = <Books as Select<'a, Book>>::Output;
+
+        fn execute(&'a self, options: Options) -> Self::Output {
+            Select::execute(&self.books, options)
+        }
+    }
+
+    impl<'a> Insert<'a, Book> for BooksTransaction<'_> {
+        type Output = <Books as Insert<'a, Book>>::Output;
+
+        fn execute(&'a self, item: Book) -> Self::Output {
+            Insert::execute(&self.books, item)
+        }
+    }
+
+    impl Transaction for BooksTransaction<'_> {
+        fn commit<'a>(self) -> FutureTransaction<'a, ()>
+        where
+            Self: 'a,
+        {
+            *self.store.0.borrow_mut() = self.books.0.into_inner();
+
+            FutureTransaction::ready(())
+        }
+
+        fn rollback<'a>(self) -> FutureTransaction<'a, ()>
+        where
+            Self: 'a,
+        {
+            FutureTransaction::ready(())
+        }
+
+        fn savepoint(&mut self, name: &str) -> FutureTransaction<'_, ()> {
+            let books = self.books.0.borrow().clone();
+
+            self.savepoints.push((name.to_owned(), books));
+
+            FutureTransaction::ready(())
+        }
+
+        fn rollback_to(&mut self, name: &str) -> FutureTransaction<'_, ()> {
+            match self
+                .savepoints
+                .iter()
+                .rposition(|(savepoint, _)| savepoint == name)
+            {
+                Some(index) => {
+                    let (_, books) = self.savepoints[index].clone();
+
+                    self.savepoints.truncate(index + 1);
+                    *self.books.0.borrow_mut() = books;
+
+                    FutureTransaction::ready(())
+                }
+                None => {
+                    FutureTransaction::error(transaction::Error::not_found("Unknown savepoint"))
+                }
+            }
+        }
+
+        fn release(&mut self, name: &str) -> FutureTransaction<'_, ()> {
+            match self
+                .savepoints
+                .iter()
+                .rposition(|(savepoint, _)| savepoint == name)
+            {
+                Some(index) => {
+                    self.savepoints.truncate(index);
+
+                    FutureTransaction::ready(())
+                }
+                None => {
+                    FutureTransaction::error(transaction::Error::not_found("Unknown savepoint"))
+                }
+            }
         }
     }
 
@@ -85,7 +716,7 @@ mod tests {
             set.insert(Book("1984"));
             set.insert(Book("Frankenstein"));
             set.insert(Book("To Kill a Mockingbird"));
-            Self(set)
+            Self(RefCell::new(set))
         }
     }
 
@@ -115,4 +746,455 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_books_select_with() -> Result<(), select::Error> {
+        let store = Books::default();
+        let options = Options::new()
+            .order_by(Order::desc("title"))
+            .with_offset(1)
+            .with_limit(1);
+        let mut books = store.select_with(options).await?;
+
+        assert_eq!(books.next().await, Some(Record::new(Book("Frankenstein"))));
+        assert_eq!(books.next().await, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_books_filter_with() -> Result<(), filter::Error> {
+        let store = Books::default();
+        let options = Options::new().order_by(Order::desc("title"));
+        let mut books = store
+            .filter_with(|item: &Book| item.0.contains('n'), options)
+            .await?;
+
+        assert_eq!(
+            books.next().await,
+            Some(Record::new(Book("To Kill a Mockingbird")))
+        );
+        assert_eq!(books.next().await, Some(Record::new(Book("Frankenstein"))));
+        assert_eq!(books.next().await, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_books_count() -> Result<(), count::Error> {
+        let store = Books::default();
+
+        assert_eq!(store.count().await?, 3);
+        assert_eq!(
+            store
+                .count_where(|item: &Book| item.0.contains('n'))
+                .await?,
+            2
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_books_exists() -> Result<(), exists::Error> {
+        let store = Books::default();
+
+        assert!(store.exists().await?);
+        assert!(store.exists_where(|item: &Book| item.0 == "1984").await?);
+        assert!(
+            !store
+                .exists_where(|item: &Book| item.0 == "Dracula")
+                .await?
+        );
+
+        store.delete(|_: &Book| true).await.unwrap();
+
+        assert!(!store.exists().await?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_books_page() -> Result<(), page::Error> {
+        let store = Books::default();
+        let options = Options::new().order_by(Order::asc("title")).with_limit(2);
+        let mut page = store.page(options.clone(), None).await?;
+
+        assert_eq!(page.next().await, Some(Record::new(Book("1984"))));
+        assert_eq!(page.next().await, Some(Record::new(Book("Frankenstein"))));
+        assert_eq!(page.next().await, None);
+
+        let cursor = page.cursor().unwrap().to_string().parse::<Cursor>()?;
+
+        store.insert(Book("Dracula")).await.unwrap();
+
+        let mut page = store.page(options, Some(cursor)).await?;
+
+        assert_eq!(
+            page.next().await,
+            Some(Record::new(Book("To Kill a Mockingbird")))
+        );
+        assert_eq!(page.next().await, None);
+        assert_eq!(page.cursor(), None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_books_page_ties() -> Result<(), page::Error> {
+        let store = Books::default();
+        let options = Options::new().order_by(Order::asc("words")).with_limit(1);
+        let mut cursor = None;
+        let mut books = Vec::new();
+
+        loop {
+            let (records, next) = store.page(options.clone(), cursor).await?.into_parts();
+
+            books.extend(records.map(Record::into_inner).collect::<Vec<_>>().await);
+
+            match next {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+
+        assert_eq!(
+            books,
+            vec![
+                Book("1984"),
+                Book("Frankenstein"),
+                Book("To Kill a Mockingbird")
+            ]
+        );
+
+        let options = Options::new().with_limit(2);
+        let (_, cursor) = store.page(options.clone(), None).await?.into_parts();
+        let mut page = store.page(options, cursor).await?;
+
+        assert_eq!(
+            page.next().await,
+            Some(Record::new(Book("To Kill a Mockingbird")))
+        );
+        assert_eq!(page.next().await, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_books_filter_expr() -> Result<(), filter::Error> {
+        let store = Books::default();
+        let expr = field("title")
+            .like("%n%")
+            .and(!field("title").eq("Frankenstein"));
+        let mut books = store.filter(&expr).await?;
+
+        assert_eq!(
+            books.next().await,
+            Some(Record::new(Book("To Kill a Mockingbird")))
+        );
+        assert_eq!(books.next().await, None);
+        assert_eq!(store.count_where(&expr).await.unwrap(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_books_filter_combinators() -> Result<(), filter::Error> {
+        let store = Books::default();
+        let short = |item: &Book| item.0.len() < 10;
+        let numeric = |item: &Book| item.0.chars().all(char::is_numeric);
+        let mut books = store.filter(short.and(numeric.not())).await?;
+
+        assert_eq!(books.next().await, None);
+
+        let mut books = store.filter(never().or(numeric)).await?;
+
+        assert_eq!(books.next().await, Some(Record::new(Book("1984"))));
+        assert_eq!(books.next().await, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_books_filter_owned() -> Result<(), filter::Error> {
+        let store = Books::default();
+        let allowed = ["1984", "Dracula"]
+            .iter()
+            .map(|title| title.to_string())
+            .collect::<Vec<_>>();
+        let mut books = store
+            .filter(move |item: &Book| allowed.iter().any(|title| title == item.0))
+            .await?;
+
+        assert_eq!(books.next().await, Some(Record::new(Book("1984"))));
+        assert_eq!(books.next().await, None);
+
+        let mut books = store.filter(field("title").eq("Frankenstein")).await?;
+
+        assert_eq!(books.next().await, Some(Record::new(Book("Frankenstein"))));
+        assert_eq!(books.next().await, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_books_project() -> Result<(), project::Error> {
+        let store = Books::default();
+        let mut rows = store.project(Projection::new(vec!["title"])).await?;
+        let row = rows.next().await.unwrap();
+
+        assert_eq!(row.get("title"), Some(&Value::from("1984")));
+        assert_eq!(row.len(), 1);
+
+        let mut rows = store
+            .project_with(
+                Projection::new(vec!["title"]),
+                field("title").like("%n%"),
+                Options::new().order_by(Order::desc("title")).with_limit(1),
+            )
+            .await?;
+
+        assert_eq!(
+            rows.next().await.map(|row| row.field("title")),
+            Some(Some(Value::from("To Kill a Mockingbird")))
+        );
+        assert_eq!(rows.next().await, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_books_aggregate() -> Result<(), aggregate::Error> {
+        let store = Books::default();
+        let mut rows = store
+            .aggregate(Aggregation::new().count("count").max("last", "title"))
+            .await?;
+        let row = rows.next().await.unwrap();
+
+        assert_eq!(row.get("count"), Some(&Value::Integer(3)));
+        assert_eq!(row.get("last"), Some(&Value::from("To Kill a Mockingbird")));
+        assert_eq!(rows.next().await, None);
+
+        let mut rows = store
+            .aggregate_with(
+                Aggregation::new().group_by("title").count("count"),
+                field("title").like("%n%"),
+                Options::new().order_by(Order::asc("title")),
+            )
+            .await?;
+
+        assert_eq!(
+            rows.next().await.and_then(|row| row.field("title")),
+            Some(Value::from("Frankenstein"))
+        );
+        assert_eq!(
+            rows.next().await.and_then(|row| row.field("title")),
+            Some(Value::from("To Kill a Mockingbird"))
+        );
+        assert_eq!(rows.next().await, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_books_transaction() -> Result<(), transaction::Error> {
+        let store = Books::default();
+        let tx = store.begin(Isolation::Serializable).await?;
+
+        tx.insert(Book("Dracula")).await.unwrap();
+
+        assert_eq!(tx.select().await.unwrap().count().await, 4);
+        assert_eq!(store.items().len(), 3);
+
+        tx.commit().await?;
+
+        assert_eq!(store.items().len(), 4);
+
+        let tx = store.begin(Isolation::ReadCommitted).await?;
+
+        tx.insert(Book("Emma")).await.unwrap();
+        tx.rollback().await?;
+
+        assert_eq!(store.items().len(), 4);
+
+        {
+            let tx = store.begin(Isolation::ReadCommitted).await?;
+
+            tx.insert(Book("Emma")).await.unwrap();
+        }
+
+        assert_eq!(store.items().len(), 4);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_books_transaction_savepoint() -> Result<(), transaction::Error> {
+        let store = Books::default();
+        let mut tx = store.begin(Isolation::ReadCommitted).await?;
+
+        tx.insert(Book("Dracula")).await.unwrap();
+        tx.savepoint("before_emma").await?;
+        tx.insert(Book("Emma")).await.unwrap();
+        tx.rollback_to("before_emma").await?;
+        tx.release("before_emma").await?;
+
+        assert!(tx.rollback_to("before_emma").await.is_err());
+
+        tx.commit().await?;
+
+        assert_eq!(
+            store.items(),
+            vec![
+                Book("1984"),
+                Book("Frankenstein"),
+                Book("To Kill a Mockingbird"),
+                Book("Dracula"),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_books_get() -> Result<(), get::Error> {
+        let store = Books::default();
+        let book = store.get("Frankenstein").await?;
+
+        assert_eq!(book, Some(Record::new(Book("Frankenstein"))));
+        assert_eq!(book.map(|book| book.key()), Some("Frankenstein"));
+        assert_eq!(store.get("Dracula").await?, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_books_insert() -> Result<(), insert::Error> {
+        let store = Books::default();
+        let book = store.insert(Book("Dracula")).await?;
+
+        assert_eq!(book, Record::new(Book("Dracula")));
+        assert_eq!(store.items().len(), 4);
+        assert_eq!(
+            store.insert(Book("Dracula")).await.unwrap_err().kind(),
+            ErrorKind::Conflict
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_books_update() -> Result<(), update::Error> {
+        let store = Books::default();
+        let mut books = store
+            .update(
+                |item: &Book| item.0 == "1984",
+                |item: &mut Book| item.0 = "Nineteen Eighty-Four",
+            )
+            .await?;
+
+        assert_eq!(
+            books.next().await,
+            Some(Record::new(Book("Nineteen Eighty-Four")))
+        );
+        assert_eq!(books.next().await, None);
+        assert_eq!(store.items()[0], Book("Nineteen Eighty-Four"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_books_delete() -> Result<(), delete::Error> {
+        let store = Books::default();
+        let count = store.delete(|item: &Book| item.0.contains('n')).await?;
+
+        assert_eq!(count, 2);
+        assert_eq!(store.items(), vec![Book("1984")]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_books_upsert() -> Result<(), upsert::Error> {
+        let store = Books::default();
+        let book = store.upsert(Book("Frankenstein")).await?;
+
+        assert_eq!(book, Record::new(Book("Frankenstein")));
+        assert_eq!(store.items().len(), 3);
+
+        let book = store.upsert(Book("Dracula")).await?;
+
+        assert_eq!(book, Record::new(Book("Dracula")));
+        assert_eq!(store.items().len(), 4);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_books_insert_many() -> Result<(), batch::Error> {
+        let store = Books::default();
+        let batch = store
+            .insert_many(iter(vec![Book("Dracula"), Book("1984"), Book("Emma")]))
+            .await?;
+
+        assert_eq!(batch.len(), 3);
+        assert!(!batch.is_ok());
+        assert_eq!(
+            batch.records().cloned().collect::<Vec<_>>(),
+            vec![Record::new(Book("Dracula")), Record::new(Book("Emma"))]
+        );
+        assert_eq!(
+            batch
+                .failures()
+                .map(|(index, error)| (index, error.kind()))
+                .collect::<Vec<_>>(),
+            vec![(1, ErrorKind::Conflict)]
+        );
+        assert_eq!(store.items().len(), 5);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_books_delete_many() -> Result<(), batch::Error> {
+        let store = Books::default();
+        let batch = store.delete_many(iter(vec!["1984", "Dracula"])).await?;
+
+        assert_eq!(batch.len(), 2);
+        assert_eq!(
+            batch.failures().map(|(index, _)| index).collect::<Vec<_>>(),
+            vec![1]
+        );
+        assert_eq!(
+            store.items(),
+            vec![Book("Frankenstein"), Book("To Kill a Mockingbird")]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_books_iter() -> Result<(), batch::Error> {
+        let store = Books::default();
+        let batch = store.insert_iter(vec![Book("Emma")]).await?;
+
+        assert!(batch.is_ok());
+
+        let batch = store.delete_iter(vec!["Emma", "Dracula"]).await?;
+
+        assert_eq!(
+            batch.records().cloned().collect::<Vec<_>>(),
+            vec![Record::new(Book("Emma"))]
+        );
+        assert_eq!(
+            batch
+                .failures()
+                .map(|(index, error)| (index, error.kind()))
+                .collect::<Vec<_>>(),
+            vec![(1, ErrorKind::NotFound)]
+        );
+        assert_eq!(store.items().len(), 3);
+
+        Ok(())
+    }
 }
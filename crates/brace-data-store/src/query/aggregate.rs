@@ -0,0 +1,352 @@
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use brace_util_future::result::FutureResult;
+
+use crate::field::{Fields, Value};
+use crate::query::options::Options;
+use crate::query::predicate::Predicate;
+use crate::query::project::Row;
+use crate::record::Records;
+
+pub use crate::error::Error;
+
+pub trait Aggregate<'a, T, P>
+where
+    P: Predicate<T>,
+{
+    type Output: Future<Output = Result<Records<'a, Row>, Error>>;
+
+    fn execute(
+        &'a self,
+        aggregation: Aggregation,
+        predicate: Option<P>,
+        options: Options,
+    ) -> Self::Output;
+}
+
+pub struct FutureAggregate<'a, T, P>(
+    Pin<Box<FutureResult<'a, Records<'a, Row>, Error>>>,
+    PhantomData<&'a (T, P)>,
+);
+
+impl<'a, T, P> FutureAggregate<'a, T, P> {
+    pub fn new<F>(future: F) -> Self
+    where
+        F: Future<Output = Result<Records<'a, Row>, Error>> + 'a,
+    {
+        Self(Box::pin(FutureResult::from_future(future)), PhantomData)
+    }
+}
+
+impl<'a, T, P> Future for FutureAggregate<'a, T, P> {
+    type Output = Result<Records<'a, Row>, Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.as_mut().poll(cx)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Function {
+    Count,
+    Sum(String),
+    Avg(String),
+    Min(String),
+    Max(String),
+}
+
+impl Function {
+    pub fn field(&self) -> Option<&str> {
+        match self {
+            Self::Count => None,
+            Self::Sum(field) | Self::Avg(field) | Self::Min(field) | Self::Max(field) => {
+                Some(field)
+            }
+        }
+    }
+
+    pub fn apply<T>(&self, items: &[&T]) -> Option<Value>
+    where
+        T: Fields,
+    {
+        let values = || {
+            items
+                .iter()
+                .filter_map(move |item| item.field(self.field().unwrap_or_default()))
+        };
+
+        match self {
+            Self::Count => Some(Value::Integer(items.len() as i64)),
+            Self::Sum(_) => sum(values()).map(|(sum, _)| sum),
+            Self::Avg(_) => sum(values()).and_then(|(sum, count)| match sum {
+                Value::Integer(sum) => Some(Value::Float(sum as f64 / count as f64)),
+                Value::Float(sum) => Some(Value::Float(sum / count as f64)),
+                _ => None,
+            }),
+            Self::Min(_) => values().fold(None, |min, value| match min {
+                Some(min) if value >= min => Some(min),
+                _ => Some(value),
+            }),
+            Self::Max(_) => values().fold(None, |max, value| match max {
+                Some(max) if value <= max => Some(max),
+                _ => Some(value),
+            }),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Aggregation {
+    group_by: Vec<String>,
+    functions: Vec<(String, Function)>,
+}
+
+impl Aggregation {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn group_by<T>(mut self, field: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.group_by.push(field.into());
+        self
+    }
+
+    pub fn with_function<T>(mut self, name: T, function: Function) -> Self
+    where
+        T: Into<String>,
+    {
+        self.functions.push((name.into(), function));
+        self
+    }
+
+    pub fn count<T>(self, name: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.with_function(name, Function::Count)
+    }
+
+    pub fn sum<T, U>(self, name: T, field: U) -> Self
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        self.with_function(name, Function::Sum(field.into()))
+    }
+
+    pub fn avg<T, U>(self, name: T, field: U) -> Self
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        self.with_function(name, Function::Avg(field.into()))
+    }
+
+    pub fn min<T, U>(self, name: T, field: U) -> Self
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        self.with_function(name, Function::Min(field.into()))
+    }
+
+    pub fn max<T, U>(self, name: T, field: U) -> Self
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        self.with_function(name, Function::Max(field.into()))
+    }
+
+    pub fn groups(&self) -> &[String] {
+        &self.group_by
+    }
+
+    pub fn functions(&self) -> &[(String, Function)] {
+        &self.functions
+    }
+
+    pub fn apply<'b, T, I>(&self, items: I) -> Vec<Row>
+    where
+        T: Fields + 'b,
+        I: IntoIterator<Item = &'b T>,
+    {
+        let mut groups: BTreeMap<Vec<Option<Value>>, Vec<&T>> = BTreeMap::new();
+
+        for item in items {
+            let key = self
+                .group_by
+                .iter()
+                .map(|field| item.field(field))
+                .collect::<Vec<_>>();
+
+            groups.entry(key).or_default().push(item);
+        }
+
+        if groups.is_empty() && self.group_by.is_empty() {
+            groups.insert(Vec::new(), Vec::new());
+        }
+
+        groups
+            .into_iter()
+            .map(|(key, items)| {
+                let mut row = Row::new();
+
+                for (field, value) in self.group_by.iter().zip(key) {
+                    if let Some(value) = value {
+                        row.insert(field.as_str(), value);
+                    }
+                }
+
+                for (name, function) in &self.functions {
+                    if let Some(value) = function.apply(&items) {
+                        row.insert(name.as_str(), value);
+                    }
+                }
+
+                row
+            })
+            .collect()
+    }
+}
+
+fn sum<I>(values: I) -> Option<(Value, usize)>
+where
+    I: Iterator<Item = Value>,
+{
+    values
+        .filter(|value| matches!(value, Value::Integer(_) | Value::Float(_)))
+        .fold(None, |acc, value| {
+            let (sum, count) = acc.unwrap_or((Value::Integer(0), 0));
+
+            let sum = match (sum, value) {
+                (Value::Integer(a), Value::Integer(b)) => match a.checked_add(b) {
+                    Some(sum) => Value::Integer(sum),
+                    None => Value::Float(a as f64 + b as f64),
+                },
+                (Value::Integer(a), Value::Float(b)) => Value::Float(a as f64 + b),
+                (Value::Float(a), Value::Integer(b)) => Value::Float(a + b as f64),
+                (Value::Float(a), Value::Float(b)) => Value::Float(a + b),
+                (sum, _) => sum,
+            };
+
+            Some((sum, count + 1))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::field::{Fields, Value};
+
+    use super::Aggregation;
+
+    struct Sale(&'static str, Option<i64>, f64);
+
+    impl Fields for Sale {
+        fn field(&self, name: &str) -> Option<Value> {
+            match name {
+                "region" => Some(self.0.into()),
+                "units" => self.1.map(Value::from),
+                "price" => Some(self.2.into()),
+                _ => None,
+            }
+        }
+    }
+
+    fn sales() -> Vec<Sale> {
+        vec![
+            Sale("north", Some(3), 2.5),
+            Sale("south", Some(1), 4.0),
+            Sale("north", None, 1.5),
+            Sale("north", Some(5), 3.0),
+        ]
+    }
+
+    #[test]
+    fn test_aggregation_group_by() {
+        let rows = Aggregation::new()
+            .group_by("region")
+            .count("count")
+            .sum("units", "units")
+            .avg("price", "price")
+            .min("min", "units")
+            .max("max", "price")
+            .apply(&sales());
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].get("region"), Some(&Value::from("north")));
+        assert_eq!(rows[0].get("count"), Some(&Value::Integer(3)));
+        assert_eq!(rows[0].get("units"), Some(&Value::Integer(8)));
+        assert_eq!(rows[0].get("price"), Some(&Value::Float(7.0 / 3.0)));
+        assert_eq!(rows[0].get("min"), Some(&Value::Integer(3)));
+        assert_eq!(rows[0].get("max"), Some(&Value::Float(3.0)));
+        assert_eq!(rows[1].get("region"), Some(&Value::from("south")));
+        assert_eq!(rows[1].get("units"), Some(&Value::Integer(1)));
+    }
+
+    #[test]
+    fn test_aggregation_total() {
+        let rows = Aggregation::new()
+            .count("count")
+            .sum("total", "price")
+            .apply(&sales());
+
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].get("count"), Some(&Value::Integer(4)));
+        assert_eq!(rows[0].get("total"), Some(&Value::Float(11.0)));
+
+        let rows = Aggregation::new()
+            .count("count")
+            .sum("total", "price")
+            .apply(&Vec::<Sale>::new());
+
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].get("count"), Some(&Value::Integer(0)));
+        assert_eq!(rows[0].get("total"), None);
+    }
+
+    #[test]
+    fn test_aggregation_sum_numeric() {
+        struct Item(Value);
+
+        impl Fields for Item {
+            fn field(&self, _: &str) -> Option<Value> {
+                Some(self.0.clone())
+            }
+        }
+
+        let rows = Aggregation::new()
+            .sum("sum", "value")
+            .avg("avg", "value")
+            .apply(&[
+                Item(Value::Integer(2)),
+                Item(Value::from("x")),
+                Item(Value::Integer(4)),
+            ]);
+
+        assert_eq!(rows[0].get("sum"), Some(&Value::Integer(6)));
+        assert_eq!(rows[0].get("avg"), Some(&Value::Float(3.0)));
+
+        let rows = Aggregation::new()
+            .sum("sum", "value")
+            .apply(&[Item(Value::Integer(i64::MAX)), Item(Value::Integer(1))]);
+
+        assert_eq!(
+            rows[0].get("sum"),
+            Some(&Value::Float(i64::MAX as f64 + 1.0))
+        );
+
+        let rows = Aggregation::new()
+            .sum("sum", "value")
+            .apply(&[Item(Value::from("x"))]);
+
+        assert_eq!(rows[0].get("sum"), None);
+    }
+}
@@ -0,0 +1,121 @@
+use std::future::Future;
+use std::iter::FromIterator;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::vec::IntoIter;
+
+use futures::stream::Stream;
+
+use brace_util_future::result::FutureResult;
+
+use crate::key::Keyed;
+use crate::record::Record;
+
+pub use crate::error::Error;
+
+pub trait InsertMany<'a, T, S>
+where
+    S: Stream<Item = T>,
+{
+    type Output: Future<Output = Result<Batch<T>, Error>>;
+
+    fn execute(&'a self, items: S) -> Self::Output;
+}
+
+pub trait DeleteMany<'a, T, S>
+where
+    T: Keyed,
+    S: Stream<Item = T::Key>,
+{
+    type Output: Future<Output = Result<Batch<T>, Error>>;
+
+    fn execute(&'a self, keys: S) -> Self::Output;
+}
+
+pub struct FutureBatch<'a, T>(Pin<Box<FutureResult<'a, Batch<T>, Error>>>);
+
+impl<'a, T> FutureBatch<'a, T> {
+    pub fn new<F>(future: F) -> Self
+    where
+        F: Future<Output = Result<Batch<T>, Error>> + 'a,
+    {
+        Self(Box::pin(FutureResult::from_future(future)))
+    }
+}
+
+impl<'a, T> Future for FutureBatch<'a, T> {
+    type Output = Result<Batch<T>, Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.as_mut().poll(cx)
+    }
+}
+
+#[derive(Debug)]
+pub struct Batch<T>(Vec<Result<Record<T>, Error>>);
+
+impl<T> Batch<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, result: Result<Record<T>, Error>) {
+        self.0.push(result);
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.0.iter().all(Result::is_ok)
+    }
+
+    pub fn records(&self) -> impl Iterator<Item = &Record<T>> {
+        self.0.iter().filter_map(|result| result.as_ref().ok())
+    }
+
+    pub fn failures(&self) -> impl Iterator<Item = (usize, &Error)> {
+        self.0
+            .iter()
+            .enumerate()
+            .filter_map(|(index, result)| result.as_ref().err().map(|err| (index, err)))
+    }
+}
+
+impl<T> Default for Batch<T> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<T> FromIterator<Result<Record<T>, Error>> for Batch<T> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Result<Record<T>, Error>>,
+    {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<T> Extend<Result<Record<T>, Error>> for Batch<T> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = Result<Record<T>, Error>>,
+    {
+        self.0.extend(iter);
+    }
+}
+
+impl<T> IntoIterator for Batch<T> {
+    type Item = Result<Record<T>, Error>;
+    type IntoIter = IntoIter<Result<Record<T>, Error>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
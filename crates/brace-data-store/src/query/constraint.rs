@@ -0,0 +1,94 @@
+use brace_data::constraint::Constrain;
+use brace_data::Data;
+
+use crate::query::predicate::Predicate;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Valid<C>(C);
+
+impl<C> Valid<C> {
+    pub fn new(constraint: C) -> Self {
+        Self(constraint)
+    }
+}
+
+impl<T, C> Predicate<T> for Valid<C>
+where
+    T: Data,
+    C: Constrain<T>,
+{
+    fn test(&self, item: &T) -> bool {
+        self.0.constrain(item).is_ok()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Invalid<C>(C);
+
+impl<C> Invalid<C> {
+    pub fn new(constraint: C) -> Self {
+        Self(constraint)
+    }
+}
+
+impl<T, C> Predicate<T> for Invalid<C>
+where
+    T: Data,
+    C: Constrain<T>,
+{
+    fn test(&self, item: &T) -> bool {
+        self.0.constrain(item).is_err()
+    }
+}
+
+pub fn valid<C>(constraint: C) -> Valid<C> {
+    Valid::new(constraint)
+}
+
+pub fn invalid<C>(constraint: C) -> Invalid<C> {
+    Invalid::new(constraint)
+}
+
+#[cfg(test)]
+mod tests {
+    use brace_data::constraint::Constraints;
+    use brace_data::{Definition, MaxLength, MinLength, Pattern, Text, TextDefinition};
+
+    use crate::query::predicate::Predicate;
+
+    use super::{invalid, valid};
+
+    #[test]
+    fn test_valid_constraint() {
+        let predicate = valid(MinLength(3));
+
+        assert!(predicate.test(&Text::new("abc")));
+        assert!(!predicate.test(&Text::new("ab")));
+    }
+
+    #[test]
+    fn test_valid_constraints() {
+        let mut constraints = Constraints::new();
+
+        constraints.insert(MinLength(2));
+        constraints.insert(MaxLength(4));
+
+        let predicate = valid(constraints);
+
+        assert!(predicate.test(&Text::new("abc")));
+        assert!(!predicate.test(&Text::new("a")));
+        assert!(!predicate.test(&Text::new("abcde")));
+    }
+
+    #[test]
+    fn test_invalid_definition() {
+        let definition = TextDefinition::new()
+            .with_constraint(MinLength(3))
+            .with_constraint(Pattern::new("^[a-z]+$").unwrap());
+        let predicate = invalid(definition);
+
+        assert!(!predicate.test(&Text::new("abc")));
+        assert!(predicate.test(&Text::new("ab")));
+        assert!(predicate.test(&Text::new("ABC")));
+    }
+}
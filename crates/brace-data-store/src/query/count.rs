@@ -0,0 +1,41 @@
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use brace_util_future::result::FutureResult;
+
+use crate::query::predicate::Predicate;
+
+pub use crate::error::Error;
+
+pub trait Count<'a, T, P>
+where
+    P: Predicate<T>,
+{
+    type Output: Future<Output = Result<usize, Error>>;
+
+    fn execute(&'a self, predicate: Option<P>) -> Self::Output;
+}
+
+pub struct FutureCount<'a, T, P>(
+    Pin<Box<FutureResult<'a, usize, Error>>>,
+    PhantomData<&'a (T, P)>,
+);
+
+impl<'a, T, P> FutureCount<'a, T, P> {
+    pub fn new<F>(future: F) -> Self
+    where
+        F: Future<Output = Result<usize, Error>> + 'a,
+    {
+        Self(Box::pin(FutureResult::from_future(future)), PhantomData)
+    }
+}
+
+impl<'a, T, P> Future for FutureCount<'a, T, P> {
+    type Output = Result<usize, Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.as_mut().poll(cx)
+    }
+}
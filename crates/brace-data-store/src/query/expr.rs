@@ -0,0 +1,327 @@
+use std::cmp::Ordering;
+use std::iter::once;
+use std::ops::Not;
+
+use crate::field::{Fields, Value};
+use crate::query::predicate::Predicate;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expr {
+    Eq(String, Value),
+    Ne(String, Value),
+    Lt(String, Value),
+    Le(String, Value),
+    Gt(String, Value),
+    Ge(String, Value),
+    In(String, Vec<Value>),
+    Like(String, String),
+    IsNull(String),
+    And(Vec<Expr>),
+    Or(Vec<Expr>),
+    Not(Box<Expr>),
+}
+
+impl Expr {
+    pub fn and(self, other: Expr) -> Self {
+        match self {
+            Self::And(mut exprs) => {
+                exprs.push(other);
+                Self::And(exprs)
+            }
+            expr => Self::And(vec![expr, other]),
+        }
+    }
+
+    pub fn or(self, other: Expr) -> Self {
+        match self {
+            Self::Or(mut exprs) => {
+                exprs.push(other);
+                Self::Or(exprs)
+            }
+            expr => Self::Or(vec![expr, other]),
+        }
+    }
+
+    pub fn evaluate<T>(&self, item: &T) -> bool
+    where
+        T: Fields,
+    {
+        match self {
+            Self::Eq(field, value) => compare(item, field, value) == Some(Ordering::Equal),
+            Self::Ne(field, value) => match compare(item, field, value) {
+                Some(ordering) => ordering != Ordering::Equal,
+                None => item.field(field).is_some(),
+            },
+            Self::Lt(field, value) => compare(item, field, value) == Some(Ordering::Less),
+            Self::Le(field, value) => matches!(
+                compare(item, field, value),
+                Some(Ordering::Less) | Some(Ordering::Equal)
+            ),
+            Self::Gt(field, value) => compare(item, field, value) == Some(Ordering::Greater),
+            Self::Ge(field, value) => matches!(
+                compare(item, field, value),
+                Some(Ordering::Greater) | Some(Ordering::Equal)
+            ),
+            Self::In(field, values) => values
+                .iter()
+                .any(|value| compare(item, field, value) == Some(Ordering::Equal)),
+            Self::Like(field, pattern) => match item.field(field) {
+                Some(Value::Text(text)) => like(&text, pattern),
+                _ => false,
+            },
+            Self::IsNull(field) => item.field(field).is_none(),
+            Self::And(exprs) => exprs.iter().all(|expr| expr.evaluate(item)),
+            Self::Or(exprs) => exprs.iter().any(|expr| expr.evaluate(item)),
+            Self::Not(expr) => !expr.evaluate(item),
+        }
+    }
+}
+
+impl Not for Expr {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        match self {
+            Self::Not(expr) => *expr,
+            expr => Self::Not(Box::new(expr)),
+        }
+    }
+}
+
+impl<T> Predicate<T> for Expr
+where
+    T: Fields,
+{
+    fn test(&self, item: &T) -> bool {
+        self.evaluate(item)
+    }
+}
+
+impl<T> Predicate<T> for &Expr
+where
+    T: Fields,
+{
+    fn test(&self, item: &T) -> bool {
+        self.evaluate(item)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Field(String);
+
+impl Field {
+    pub fn eq<T>(self, value: T) -> Expr
+    where
+        T: Into<Value>,
+    {
+        Expr::Eq(self.0, value.into())
+    }
+
+    pub fn ne<T>(self, value: T) -> Expr
+    where
+        T: Into<Value>,
+    {
+        Expr::Ne(self.0, value.into())
+    }
+
+    pub fn lt<T>(self, value: T) -> Expr
+    where
+        T: Into<Value>,
+    {
+        Expr::Lt(self.0, value.into())
+    }
+
+    pub fn le<T>(self, value: T) -> Expr
+    where
+        T: Into<Value>,
+    {
+        Expr::Le(self.0, value.into())
+    }
+
+    pub fn gt<T>(self, value: T) -> Expr
+    where
+        T: Into<Value>,
+    {
+        Expr::Gt(self.0, value.into())
+    }
+
+    pub fn ge<T>(self, value: T) -> Expr
+    where
+        T: Into<Value>,
+    {
+        Expr::Ge(self.0, value.into())
+    }
+
+    pub fn is_in<I>(self, values: I) -> Expr
+    where
+        I: IntoIterator,
+        I::Item: Into<Value>,
+    {
+        Expr::In(self.0, values.into_iter().map(Into::into).collect())
+    }
+
+    pub fn like<T>(self, pattern: T) -> Expr
+    where
+        T: Into<String>,
+    {
+        Expr::Like(self.0, pattern.into())
+    }
+
+    pub fn is_null(self) -> Expr {
+        Expr::IsNull(self.0)
+    }
+
+    pub fn is_not_null(self) -> Expr {
+        !Expr::IsNull(self.0)
+    }
+}
+
+pub fn field<T>(name: T) -> Field
+where
+    T: Into<String>,
+{
+    Field(name.into())
+}
+
+fn compare<T>(item: &T, field: &str, value: &Value) -> Option<Ordering>
+where
+    T: Fields,
+{
+    let current = item.field(field)?;
+
+    match (&current, value) {
+        (Value::Text(_), Value::Text(_))
+        | (Value::Bool(_), Value::Bool(_))
+        | (Value::Integer(_), Value::Integer(_))
+        | (Value::Integer(_), Value::Float(_))
+        | (Value::Float(_), Value::Integer(_))
+        | (Value::Float(_), Value::Float(_)) => current.partial_cmp(value),
+        _ => None,
+    }
+}
+
+fn like(text: &str, pattern: &str) -> bool {
+    let text = text.chars().collect::<Vec<_>>();
+    let mut tokens = Vec::new();
+    let mut chars = pattern.chars();
+
+    while let Some(c) = chars.next() {
+        tokens.push(match c {
+            '%' => Token::Many,
+            '_' => Token::One,
+            '\\' => Token::Char(chars.next().unwrap_or('\\')),
+            c => Token::Char(c),
+        });
+    }
+
+    let mut matches = vec![false; text.len() + 1];
+
+    matches[0] = true;
+
+    for token in tokens {
+        matches = match token {
+            Token::Many => {
+                let mut any = false;
+
+                matches
+                    .iter()
+                    .map(|matched| {
+                        any = any || *matched;
+                        any
+                    })
+                    .collect()
+            }
+            Token::One => once(false)
+                .chain(matches[..text.len()].iter().copied())
+                .collect(),
+            Token::Char(c) => once(false)
+                .chain(
+                    matches
+                        .iter()
+                        .zip(&text)
+                        .map(|(matched, current)| *matched && *current == c),
+                )
+                .collect(),
+        };
+    }
+
+    matches[text.len()]
+}
+
+enum Token {
+    Many,
+    One,
+    Char(char),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::field::{Fields, Value};
+    use crate::query::predicate::Predicate;
+
+    use super::{field, like, Expr};
+
+    struct Person(&'static str, Option<i64>);
+
+    impl Fields for Person {
+        fn field(&self, name: &str) -> Option<Value> {
+            match name {
+                "name" => Some(self.0.into()),
+                "age" => self.1.map(Value::from),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn test_expr_comparison() {
+        let alice = Person("Alice", Some(30));
+        let bob = Person("Bob", None);
+
+        assert!(field("age").eq(30).test(&alice));
+        assert!(field("age").ge(30).test(&alice));
+        assert!(field("age").le(30.0).test(&alice));
+        assert!(!field("age").gt(30).test(&alice));
+        assert!(field("age").lt(31).test(&alice));
+        assert!(field("age").ne(31).test(&alice));
+        assert!(!field("age").ne(31).test(&bob));
+        assert!(!field("age").eq("30").test(&alice));
+        assert!(field("name").is_in(vec!["Bob", "Alice"]).test(&alice));
+        assert!(!field("name").is_in(Vec::<Value>::new()).test(&alice));
+        assert!(field("age").is_null().test(&bob));
+        assert!(field("age").is_not_null().test(&alice));
+    }
+
+    #[test]
+    fn test_expr_logic() {
+        let alice = Person("Alice", Some(30));
+        let expr = field("age").gt(18).and(field("name").like("A%"));
+
+        assert!(expr.test(&alice));
+        assert!(!(!expr.clone()).test(&alice));
+        assert_eq!(!!expr.clone(), expr);
+        assert!(field("age")
+            .lt(18)
+            .or(field("name").eq("Alice"))
+            .test(&alice));
+        assert!(Expr::And(Vec::new()).test(&alice));
+        assert!(!Expr::Or(Vec::new()).test(&alice));
+        assert_eq!(
+            field("a").eq(1).and(field("b").eq(2)).and(field("c").eq(3)),
+            Expr::And(vec![field("a").eq(1), field("b").eq(2), field("c").eq(3)])
+        );
+    }
+
+    #[test]
+    fn test_expr_like() {
+        assert!(like("Frankenstein", "Frank%"));
+        assert!(like("Frankenstein", "%stein"));
+        assert!(like("Frankenstein", "%ken%"));
+        assert!(like("Frankenstein", "Frankenstei_"));
+        assert!(!like("Frankenstein", "frank%"));
+        assert!(!like("Frankenstein", "Frank"));
+        assert!(like("100%", "100\\%"));
+        assert!(!like("1000", "100\\%"));
+        assert!(like("", "%"));
+    }
+}
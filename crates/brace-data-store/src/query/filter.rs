@@ -1,5 +1,3 @@
-use std::error;
-use std::fmt::{self, Display};
 use std::future::Future;
 use std::marker::PhantomData;
 use std::pin::Pin;
@@ -7,15 +5,19 @@ use std::task::{Context, Poll};
 
 use brace_util_future::result::FutureResult;
 
+use crate::query::options::Options;
 use crate::record::Records;
 
+pub use crate::error::Error;
+pub use crate::query::predicate::Predicate;
+
 pub trait Filter<'a, T, P>
 where
     P: Predicate<T>,
 {
     type Output: Future<Output = Result<Records<'a, T>, Error>>;
 
-    fn execute(&'a self, predicate: P) -> Self::Output;
+    fn execute(&'a self, predicate: P, options: Options) -> Self::Output;
 }
 
 pub struct FutureFilter<'a, T, P>(
@@ -42,40 +44,3 @@ where
         self.0.as_mut().poll(cx)
     }
 }
-
-pub trait Predicate<T> {
-    fn test(&self, item: &T) -> bool;
-}
-
-impl<T, U> Predicate<T> for U
-where
-    U: Fn(&T) -> bool,
-{
-    fn test(&self, item: &T) -> bool {
-        (self)(item)
-    }
-}
-
-#[derive(Debug)]
-pub enum Error {
-    Message(String),
-}
-
-impl Error {
-    pub fn message<T>(message: T) -> Self
-    where
-        T: Into<String>,
-    {
-        Self::Message(message.into())
-    }
-}
-
-impl Display for Error {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            Self::Message(message) => message.fmt(f),
-        }
-    }
-}
-
-impl error::Error for Error {}
@@ -0,0 +1,38 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use brace_util_future::result::FutureResult;
+
+use crate::key::Keyed;
+use crate::record::Record;
+
+pub use crate::error::Error;
+
+pub trait Get<'a, T>
+where
+    T: Keyed,
+{
+    type Output: Future<Output = Result<Option<Record<T>>, Error>>;
+
+    fn execute(&'a self, key: T::Key) -> Self::Output;
+}
+
+pub struct FutureGet<'a, T>(Pin<Box<FutureResult<'a, Option<Record<T>>, Error>>>);
+
+impl<'a, T> FutureGet<'a, T> {
+    pub fn new<F>(future: F) -> Self
+    where
+        F: Future<Output = Result<Option<Record<T>>, Error>> + 'a,
+    {
+        Self(Box::pin(FutureResult::from_future(future)))
+    }
+}
+
+impl<'a, T> Future for FutureGet<'a, T> {
+    type Output = Result<Option<Record<T>>, Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.as_mut().poll(cx)
+    }
+}
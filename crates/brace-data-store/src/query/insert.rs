@@ -0,0 +1,34 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use brace_util_future::result::FutureResult;
+
+use crate::record::Record;
+
+pub use crate::error::Error;
+
+pub trait Insert<'a, T> {
+    type Output: Future<Output = Result<Record<T>, Error>>;
+
+    fn execute(&'a self, item: T) -> Self::Output;
+}
+
+pub struct FutureInsert<'a, T>(Pin<Box<FutureResult<'a, Record<T>, Error>>>);
+
+impl<'a, T> FutureInsert<'a, T> {
+    pub fn new<F>(future: F) -> Self
+    where
+        F: Future<Output = Result<Record<T>, Error>> + 'a,
+    {
+        Self(Box::pin(FutureResult::from_future(future)))
+    }
+}
+
+impl<'a, T> Future for FutureInsert<'a, T> {
+    type Output = Result<Record<T>, Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.as_mut().poll(cx)
+    }
+}
@@ -1,2 +1,20 @@
+pub mod aggregate;
+pub mod batch;
+#[cfg(feature = "data")]
+pub mod constraint;
+pub mod count;
+pub mod delete;
+pub mod exists;
+pub mod expr;
 pub mod filter;
+pub mod get;
+pub mod insert;
+pub mod options;
+pub mod page;
+pub mod predicate;
+pub mod project;
+pub mod save;
 pub mod select;
+pub mod update;
+pub mod upsert;
+pub mod with;
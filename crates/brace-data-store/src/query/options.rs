@@ -0,0 +1,269 @@
+use std::cmp::Ordering;
+
+use crate::field::{Fields, Value};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Ascending,
+    Descending,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Nulls {
+    First,
+    Last,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Order {
+    field: String,
+    direction: Direction,
+    nulls: Option<Nulls>,
+}
+
+impl Order {
+    pub fn new<T>(field: T, direction: Direction) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            field: field.into(),
+            direction,
+            nulls: None,
+        }
+    }
+
+    pub fn asc<T>(field: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::new(field, Direction::Ascending)
+    }
+
+    pub fn desc<T>(field: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::new(field, Direction::Descending)
+    }
+
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    pub fn nulls(&self) -> Nulls {
+        match (self.nulls, self.direction) {
+            (Some(nulls), _) => nulls,
+            (None, Direction::Ascending) => Nulls::Last,
+            (None, Direction::Descending) => Nulls::First,
+        }
+    }
+
+    pub fn nulls_first(mut self) -> Self {
+        self.nulls = Some(Nulls::First);
+        self
+    }
+
+    pub fn nulls_last(mut self) -> Self {
+        self.nulls = Some(Nulls::Last);
+        self
+    }
+
+    pub fn compare<T>(&self, a: &T, b: &T) -> Ordering
+    where
+        T: Fields,
+    {
+        self.compare_values(a.field(&self.field).as_ref(), b.field(&self.field).as_ref())
+    }
+
+    pub fn compare_values(&self, a: Option<&Value>, b: Option<&Value>) -> Ordering {
+        match (a, b) {
+            (Some(a), Some(b)) => {
+                let ordering = a.cmp(b);
+
+                match self.direction {
+                    Direction::Ascending => ordering,
+                    Direction::Descending => ordering.reverse(),
+                }
+            }
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => match self.nulls() {
+                Nulls::First => Ordering::Less,
+                Nulls::Last => Ordering::Greater,
+            },
+            (Some(_), None) => match self.nulls() {
+                Nulls::First => Ordering::Greater,
+                Nulls::Last => Ordering::Less,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Options {
+    order: Vec<Order>,
+    limit: Option<usize>,
+    offset: Option<usize>,
+}
+
+impl Options {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn order(&self) -> &[Order] {
+        &self.order
+    }
+
+    pub fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    pub fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    pub fn order_by(mut self, order: Order) -> Self {
+        self.order.push(order);
+        self
+    }
+
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn compare<T>(&self, a: &T, b: &T) -> Ordering
+    where
+        T: Fields,
+    {
+        self.order
+            .iter()
+            .map(|order| order.compare(a, b))
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    }
+
+    pub fn apply<T>(&self, mut items: Vec<T>) -> Vec<T>
+    where
+        T: Fields,
+    {
+        if !self.order.is_empty() {
+            items.sort_by(|a, b| self.compare(a, b));
+        }
+
+        items
+            .into_iter()
+            .skip(self.offset.unwrap_or(0))
+            .take(self.limit.unwrap_or(usize::MAX))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::field::{Fields, Value};
+
+    use super::{Options, Order};
+
+    #[derive(Debug, PartialEq)]
+    struct Person(&'static str, Option<u32>);
+
+    impl Fields for Person {
+        fn field(&self, name: &str) -> Option<Value> {
+            match name {
+                "name" => Some(self.0.into()),
+                "age" => self.1.map(Value::from),
+                _ => None,
+            }
+        }
+    }
+
+    fn people() -> Vec<Person> {
+        vec![
+            Person("Carol", Some(30)),
+            Person("Alice", None),
+            Person("Bob", Some(30)),
+            Person("Dave", Some(20)),
+        ]
+    }
+
+    #[test]
+    fn test_options_order() {
+        let options = Options::new()
+            .order_by(Order::desc("age"))
+            .order_by(Order::asc("name"));
+
+        assert_eq!(
+            options.apply(people()),
+            vec![
+                Person("Alice", None),
+                Person("Bob", Some(30)),
+                Person("Carol", Some(30)),
+                Person("Dave", Some(20)),
+            ]
+        );
+
+        let options = Options::new().order_by(Order::desc("age").nulls_last());
+
+        assert_eq!(options.apply(people()).pop(), Some(Person("Alice", None)));
+
+        let options = Options::new().order_by(Order::asc("age"));
+
+        assert_eq!(options.apply(people()).pop(), Some(Person("Alice", None)));
+    }
+
+    #[test]
+    fn test_options_limit_offset() {
+        let options = Options::new()
+            .order_by(Order::asc("name"))
+            .with_offset(1)
+            .with_limit(2);
+
+        assert_eq!(
+            options.apply(people()),
+            vec![Person("Bob", Some(30)), Person("Carol", Some(30))]
+        );
+        assert_eq!(Options::new().with_offset(4).apply(people()), vec![]);
+    }
+
+    #[test]
+    fn test_options_order_nan() {
+        struct Score(f64);
+
+        impl Fields for Score {
+            fn field(&self, name: &str) -> Option<Value> {
+                match name {
+                    "score" => Some(Value::from(self.0)),
+                    _ => None,
+                }
+            }
+        }
+
+        let scores = vec![
+            Score(2.0),
+            Score(f64::NAN),
+            Score(1.0),
+            Score(f64::NAN),
+            Score(3.0),
+        ];
+        let options = Options::new().order_by(Order::asc("score"));
+        let sorted = options
+            .apply(scores)
+            .into_iter()
+            .map(|score| score.0)
+            .collect::<Vec<_>>();
+
+        assert_eq!(&sorted[..3], &[1.0, 2.0, 3.0]);
+        assert!(sorted[3..].iter().all(|score| score.is_nan()));
+    }
+}
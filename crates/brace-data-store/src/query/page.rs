@@ -0,0 +1,286 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+use std::future::Future;
+use std::pin::Pin;
+use std::str::{self, FromStr};
+use std::task::{Context, Poll};
+
+use futures::stream::Stream;
+
+use brace_util_future::result::FutureResult;
+
+use crate::field::{Fields, Value};
+use crate::key::Keyed;
+use crate::query::options::Options;
+use crate::record::{Record, Records};
+
+pub use crate::error::Error;
+
+pub trait Paginate<'a, T>
+where
+    T: Keyed,
+{
+    type Output: Future<Output = Result<Page<'a, T>, Error>>;
+
+    fn execute(&'a self, options: Options, cursor: Option<Cursor>) -> Self::Output;
+}
+
+pub struct FuturePage<'a, T>(Pin<Box<FutureResult<'a, Page<'a, T>, Error>>>);
+
+impl<'a, T> FuturePage<'a, T> {
+    pub fn new<F>(future: F) -> Self
+    where
+        F: Future<Output = Result<Page<'a, T>, Error>> + 'a,
+    {
+        Self(Box::pin(FutureResult::from_future(future)))
+    }
+}
+
+impl<'a, T> Future for FuturePage<'a, T>
+where
+    T: 'a,
+{
+    type Output = Result<Page<'a, T>, Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.as_mut().poll(cx)
+    }
+}
+
+pub struct Page<'a, T> {
+    records: Records<'a, T>,
+    cursor: Option<Cursor>,
+}
+
+impl<'a, T> Page<'a, T> {
+    pub fn new(records: Records<'a, T>, cursor: Option<Cursor>) -> Self {
+        Self { records, cursor }
+    }
+
+    pub fn cursor(&self) -> Option<&Cursor> {
+        self.cursor.as_ref()
+    }
+
+    pub fn into_parts(self) -> (Records<'a, T>, Option<Cursor>) {
+        (self.records, self.cursor)
+    }
+}
+
+impl<'a, T> Stream for Page<'a, T>
+where
+    T: 'a,
+{
+    type Item = Record<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.records).poll_next(cx)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cursor(Vec<Option<Value>>);
+
+impl Cursor {
+    pub fn new(values: Vec<Option<Value>>) -> Self {
+        Self(values)
+    }
+
+    pub fn from_item<T>(item: &T, options: &Options) -> Self
+    where
+        T: Fields + Keyed,
+        T::Key: Into<Value>,
+    {
+        Self(
+            options
+                .order()
+                .iter()
+                .map(|order| item.field(order.field()))
+                .chain(Some(Some(item.key().into())))
+                .collect(),
+        )
+    }
+
+    pub fn values(&self) -> &[Option<Value>] {
+        &self.0
+    }
+
+    pub fn key(&self, options: &Options) -> Option<&Value> {
+        self.0.get(options.order().len()).and_then(Option::as_ref)
+    }
+
+    pub fn compare<T>(&self, item: &T, options: &Options) -> Ordering
+    where
+        T: Fields + Keyed,
+        T::Key: Into<Value>,
+    {
+        options
+            .order()
+            .iter()
+            .zip(&self.0)
+            .map(|(order, value)| {
+                order.compare_values(item.field(order.field()).as_ref(), value.as_ref())
+            })
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or_else(|| Some(&item.key().into()).cmp(&self.key(options)))
+    }
+
+    pub fn is_before<T>(&self, item: &T, options: &Options) -> bool
+    where
+        T: Fields + Keyed,
+        T::Key: Into<Value>,
+    {
+        self.compare(item, options) == Ordering::Greater
+    }
+}
+
+pub fn paginate<T>(
+    items: Vec<T>,
+    options: &Options,
+    cursor: Option<&Cursor>,
+) -> (Vec<T>, Option<Cursor>)
+where
+    T: Fields + Keyed,
+    T::Key: Into<Value>,
+{
+    let mut items = items
+        .into_iter()
+        .filter(|item| cursor.map_or(true, |cursor| cursor.is_before(item, options)))
+        .collect::<Vec<_>>();
+
+    items.sort_by_cached_key(|item| item.key().into());
+
+    match options.limit() {
+        Some(limit) => {
+            let mut items = options.clone().with_limit(limit + 1).apply(items);
+
+            if items.len() > limit {
+                items.truncate(limit);
+
+                let cursor = items.last().map(|item| Cursor::from_item(item, options));
+
+                (items, cursor)
+            } else {
+                (items, None)
+            }
+        }
+        None => (options.apply(items), None),
+    }
+}
+
+impl Display for Cursor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, value) in self.0.iter().enumerate() {
+            if index > 0 {
+                write!(f, ".")?;
+            }
+
+            let (tag, payload) = match value {
+                None => ('n', String::new()),
+                Some(Value::Bool(value)) => ('b', (*value as u8).to_string()),
+                Some(Value::Integer(value)) => ('i', value.to_string()),
+                Some(Value::Float(value)) => ('f', value.to_bits().to_string()),
+                Some(Value::Text(value)) => ('t', value.clone()),
+            };
+
+            write!(f, "{}", tag)?;
+
+            for byte in payload.bytes() {
+                write!(f, "{:02x}", byte)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Cursor {
+    type Err = Error;
+
+    fn from_str(cursor: &str) -> Result<Self, Self::Err> {
+        if cursor.is_empty() {
+            return Ok(Self(Vec::new()));
+        }
+
+        cursor
+            .split('.')
+            .map(decode)
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+fn decode(part: &str) -> Result<Option<Value>, Error> {
+    let invalid = || Error::serialization("Invalid cursor");
+    let tag = part.chars().next().ok_or_else(invalid)?;
+    let hex = &part[tag.len_utf8()..];
+
+    let bytes = hex
+        .as_bytes()
+        .chunks(2)
+        .map(|chunk| {
+            str::from_utf8(chunk)
+                .ok()
+                .filter(|chunk| chunk.len() == 2)
+                .and_then(|chunk| u8::from_str_radix(chunk, 16).ok())
+                .ok_or_else(invalid)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let payload = String::from_utf8(bytes).map_err(|_| invalid())?;
+
+    match tag {
+        'n' if payload.is_empty() => Ok(None),
+        'b' => match payload.as_str() {
+            "0" => Ok(Some(Value::Bool(false))),
+            "1" => Ok(Some(Value::Bool(true))),
+            _ => Err(invalid()),
+        },
+        'i' => payload
+            .parse()
+            .map(|value| Some(Value::Integer(value)))
+            .map_err(|_| invalid()),
+        'f' => payload
+            .parse()
+            .map(|bits| Some(Value::Float(f64::from_bits(bits))))
+            .map_err(|_| invalid()),
+        't' => Ok(Some(Value::Text(payload))),
+        _ => Err(invalid()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ErrorKind;
+    use crate::field::Value;
+
+    use super::Cursor;
+
+    #[test]
+    fn test_cursor_roundtrip() {
+        let cursor = Cursor::new(vec![
+            None,
+            Some(Value::Bool(true)),
+            Some(Value::Integer(-42)),
+            Some(Value::Float(1.5)),
+            Some(Value::Text("a.b ü".to_owned())),
+        ]);
+        let encoded = cursor.to_string();
+
+        assert!(encoded
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '.'));
+        assert_eq!(encoded.parse::<Cursor>().unwrap(), cursor);
+        assert_eq!("".parse::<Cursor>().unwrap(), Cursor::new(Vec::new()));
+    }
+
+    #[test]
+    fn test_cursor_invalid() {
+        assert_eq!(
+            "x00".parse::<Cursor>().unwrap_err().kind(),
+            ErrorKind::Serialization
+        );
+        assert!("t0".parse::<Cursor>().is_err());
+        assert!("tzz".parse::<Cursor>().is_err());
+        assert!("i61".parse::<Cursor>().is_err());
+        assert!("n61".parse::<Cursor>().is_err());
+    }
+}
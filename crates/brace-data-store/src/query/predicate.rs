@@ -0,0 +1,196 @@
+use std::sync::Arc;
+
+pub trait Predicate<T> {
+    fn test(&self, item: &T) -> bool;
+}
+
+impl<T, U> Predicate<T> for U
+where
+    U: Fn(&T) -> bool,
+{
+    fn test(&self, item: &T) -> bool {
+        (self)(item)
+    }
+}
+
+pub trait PredicateExt<T>: Predicate<T> + Sized {
+    fn and<P>(self, other: P) -> And<Self, P>
+    where
+        P: Predicate<T>,
+    {
+        And(self, other)
+    }
+
+    fn or<P>(self, other: P) -> Or<Self, P>
+    where
+        P: Predicate<T>,
+    {
+        Or(self, other)
+    }
+
+    fn not(self) -> Not<Self> {
+        Not(self)
+    }
+
+    fn shared(self) -> Shared<Self> {
+        Shared::new(self)
+    }
+}
+
+impl<T, P> PredicateExt<T> for P where P: Predicate<T> {}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Always;
+
+impl<T> Predicate<T> for Always {
+    fn test(&self, _: &T) -> bool {
+        true
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Never;
+
+impl<T> Predicate<T> for Never {
+    fn test(&self, _: &T) -> bool {
+        false
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct And<A, B>(A, B);
+
+impl<T, A, B> Predicate<T> for And<A, B>
+where
+    A: Predicate<T>,
+    B: Predicate<T>,
+{
+    fn test(&self, item: &T) -> bool {
+        self.0.test(item) && self.1.test(item)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Or<A, B>(A, B);
+
+impl<T, A, B> Predicate<T> for Or<A, B>
+where
+    A: Predicate<T>,
+    B: Predicate<T>,
+{
+    fn test(&self, item: &T) -> bool {
+        self.0.test(item) || self.1.test(item)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Not<P>(P);
+
+impl<T, P> Predicate<T> for Not<P>
+where
+    P: Predicate<T>,
+{
+    fn test(&self, item: &T) -> bool {
+        !self.0.test(item)
+    }
+}
+
+#[derive(Debug)]
+pub struct Shared<P>(Arc<P>);
+
+impl<P> Shared<P> {
+    pub fn new(predicate: P) -> Self {
+        Self(Arc::new(predicate))
+    }
+}
+
+impl<P> Clone for Shared<P> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<P> From<Arc<P>> for Shared<P> {
+    fn from(predicate: Arc<P>) -> Self {
+        Self(predicate)
+    }
+}
+
+impl<T, P> Predicate<T> for Shared<P>
+where
+    P: Predicate<T>,
+{
+    fn test(&self, item: &T) -> bool {
+        self.0.test(item)
+    }
+}
+
+pub fn always() -> Always {
+    Always
+}
+
+pub fn never() -> Never {
+    Never
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use futures::executor::block_on;
+    use futures::stream::{iter, StreamExt};
+
+    use super::{always, never, Predicate, PredicateExt};
+
+    fn even(item: &i32) -> bool {
+        item % 2 == 0
+    }
+
+    fn positive(item: &i32) -> bool {
+        *item > 0
+    }
+
+    #[test]
+    fn test_predicate_combinators() {
+        assert!(even.and(positive).test(&2));
+        assert!(!even.and(positive).test(&-2));
+        assert!(even.or(positive).test(&-2));
+        assert!(!even.or(positive).test(&-3));
+        assert!(even.not().test(&3));
+        assert!(even
+            .and(positive.not())
+            .or(|item: &i32| *item == 7)
+            .test(&7));
+    }
+
+    #[test]
+    fn test_predicate_constants() {
+        assert!(Predicate::<i32>::test(&always(), &1));
+        assert!(!Predicate::<i32>::test(&never(), &1));
+        assert!(never().or(positive).test(&1));
+        assert!(!always().and(positive).test(&-1));
+    }
+
+    #[test]
+    fn test_predicate_shared() {
+        let allowed = [2, 3, 5, 7].iter().copied().collect::<HashSet<_>>();
+        let predicate = (move |item: &i32| allowed.contains(item)).shared();
+        let items = block_on(
+            iter(1..10)
+                .filter_map(move |item| {
+                    let predicate = predicate.clone();
+
+                    async move {
+                        if predicate.test(&item) {
+                            return Some(item);
+                        }
+
+                        None
+                    }
+                })
+                .collect::<Vec<_>>(),
+        );
+
+        assert_eq!(items, vec![2, 3, 5, 7]);
+    }
+}
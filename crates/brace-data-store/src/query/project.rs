@@ -0,0 +1,204 @@
+use std::collections::btree_map::{self, BTreeMap};
+use std::future::Future;
+use std::iter::FromIterator;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use brace_util_future::result::FutureResult;
+
+use crate::field::{Fields, Value};
+use crate::query::options::Options;
+use crate::query::predicate::Predicate;
+use crate::record::Records;
+
+pub use crate::error::Error;
+
+pub trait Project<'a, T, P>
+where
+    P: Predicate<T>,
+{
+    type Output: Future<Output = Result<Records<'a, Row>, Error>>;
+
+    fn execute(
+        &'a self,
+        projection: Projection,
+        predicate: Option<P>,
+        options: Options,
+    ) -> Self::Output;
+}
+
+pub struct FutureProject<'a, T, P>(
+    Pin<Box<FutureResult<'a, Records<'a, Row>, Error>>>,
+    PhantomData<&'a (T, P)>,
+);
+
+impl<'a, T, P> FutureProject<'a, T, P> {
+    pub fn new<F>(future: F) -> Self
+    where
+        F: Future<Output = Result<Records<'a, Row>, Error>> + 'a,
+    {
+        Self(Box::pin(FutureResult::from_future(future)), PhantomData)
+    }
+}
+
+impl<'a, T, P> Future for FutureProject<'a, T, P> {
+    type Output = Result<Records<'a, Row>, Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.as_mut().poll(cx)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Projection(Vec<String>);
+
+impl Projection {
+    pub fn new<I>(fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        Self(fields.into_iter().map(Into::into).collect())
+    }
+
+    pub fn fields(&self) -> &[String] {
+        &self.0
+    }
+
+    pub fn apply<T>(&self, item: &T) -> Row
+    where
+        T: Fields,
+    {
+        self.0
+            .iter()
+            .filter_map(|name| item.field(name).map(|value| (name.clone(), value)))
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Row(BTreeMap<String, Value>);
+
+impl Row {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.0.get(name)
+    }
+
+    pub fn insert<T, U>(&mut self, name: T, value: U) -> Option<Value>
+    where
+        T: Into<String>,
+        U: Into<Value>,
+    {
+        self.0.insert(name.into(), value.into())
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<Value> {
+        self.0.remove(name)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> btree_map::Iter<'_, String, Value> {
+        self.0.iter()
+    }
+}
+
+impl Fields for Row {
+    fn field(&self, name: &str) -> Option<Value> {
+        self.0.get(name).cloned()
+    }
+}
+
+impl<T> FromIterator<(T, Value)> for Row
+where
+    T: Into<String>,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (T, Value)>,
+    {
+        Self(
+            iter.into_iter()
+                .map(|(name, value)| (name.into(), value))
+                .collect(),
+        )
+    }
+}
+
+impl IntoIterator for Row {
+    type Item = (String, Value);
+    type IntoIter = btree_map::IntoIter<String, Value>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Row {
+    type Item = (&'a String, &'a Value);
+    type IntoIter = btree_map::Iter<'a, String, Value>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::field::{Fields, Value};
+
+    use super::{Projection, Row};
+
+    struct Article(u32, &'static str, &'static str);
+
+    impl Fields for Article {
+        fn field(&self, name: &str) -> Option<Value> {
+            match name {
+                "id" => Some(self.0.into()),
+                "title" => Some(self.1.into()),
+                "body" => Some(self.2.into()),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn test_projection_apply() {
+        let article = Article(1, "Title", "A very long body");
+        let row = Projection::new(vec!["id", "title", "missing"]).apply(&article);
+
+        assert_eq!(row.len(), 2);
+        assert_eq!(row.get("id"), Some(&Value::Integer(1)));
+        assert_eq!(row.field("title"), Some(Value::from("Title")));
+        assert_eq!(row.get("body"), None);
+        assert!(Projection::default().apply(&article).is_empty());
+    }
+
+    #[test]
+    fn test_row() {
+        let mut row = Row::new();
+
+        row.insert("id", 1);
+        row.insert("title", "Title");
+
+        assert_eq!(
+            row.iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["id", "title"]
+        );
+        assert_eq!(row.remove("id"), Some(Value::Integer(1)));
+        assert_eq!(row.into_iter().count(), 1);
+    }
+}
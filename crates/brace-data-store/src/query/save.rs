@@ -0,0 +1,199 @@
+use std::error::Error as StdError;
+use std::fmt::{self, Display};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use brace_util_future::result::FutureResult;
+
+use crate::error::{self, ErrorKind};
+use crate::record::Record;
+
+pub trait Save<'a, T> {
+    type Output: Future<Output = Result<Record<T>, Error<T>>>;
+
+    fn execute(&'a self, record: Record<T>) -> Self::Output;
+}
+
+pub struct FutureSave<'a, T>(Pin<Box<FutureResult<'a, Record<T>, Error<T>>>>);
+
+impl<'a, T> FutureSave<'a, T> {
+    pub fn new<F>(future: F) -> Self
+    where
+        F: Future<Output = Result<Record<T>, Error<T>>> + 'a,
+    {
+        Self(Box::pin(FutureResult::from_future(future)))
+    }
+}
+
+impl<'a, T> Future for FutureSave<'a, T> {
+    type Output = Result<Record<T>, Error<T>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.as_mut().poll(cx)
+    }
+}
+
+#[derive(Debug)]
+pub enum Error<T> {
+    Conflict(Box<Record<T>>),
+    Store(error::Error),
+}
+
+impl<T> Error<T> {
+    pub fn conflict(current: Record<T>) -> Self {
+        Self::Conflict(Box::new(current))
+    }
+
+    pub fn message<U>(message: U) -> Self
+    where
+        U: Into<String>,
+    {
+        Self::Store(error::Error::message(message))
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Conflict(_) => ErrorKind::Conflict,
+            Self::Store(error) => error.kind(),
+        }
+    }
+
+    pub fn is_conflict(&self) -> bool {
+        match self {
+            Self::Conflict(_) => true,
+            Self::Store(_) => false,
+        }
+    }
+
+    pub fn current(&self) -> Option<&Record<T>> {
+        match self {
+            Self::Conflict(current) => Some(current),
+            Self::Store(_) => None,
+        }
+    }
+
+    pub fn into_current(self) -> Option<Record<T>> {
+        match self {
+            Self::Conflict(current) => Some(*current),
+            Self::Store(_) => None,
+        }
+    }
+}
+
+impl<T> Display for Error<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Conflict(current) => match current.meta().revision() {
+                Some(revision) => write!(f, "Revision conflict, current revision is {}", revision),
+                None => write!(f, "Revision conflict"),
+            },
+            Self::Store(error) => error.fmt(f),
+        }
+    }
+}
+
+impl<T> StdError for Error<T>
+where
+    T: fmt::Debug,
+{
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::Conflict(_) => None,
+            Self::Store(error) => Some(error),
+        }
+    }
+}
+
+impl<T> From<error::Error> for Error<T> {
+    fn from(error: error::Error) -> Self {
+        Self::Store(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    use futures::future::{err, ok, Ready};
+
+    use crate::error::{self, ErrorKind};
+    use crate::record::{Meta, Record};
+    use crate::Store;
+
+    use super::{Error, Save};
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Article(u32, &'static str);
+
+    struct Articles(RefCell<HashMap<u32, Record<Article>>>);
+
+    impl Store for Articles {
+        type Item = Article;
+    }
+
+    impl<'a> Save<'a, Article> for Articles {
+        type Output = Ready<Result<Record<Article>, Error<Article>>>;
+
+        fn execute(&'a self, mut record: Record<Article>) -> Self::Output {
+            let mut articles = self.0.borrow_mut();
+            let current = match articles.get(&record.0) {
+                Some(current) => current,
+                None => return err(error::Error::not_found("Article does not exist").into()),
+            };
+
+            let revision = match current.meta().revision() {
+                Some(revision) => revision,
+                None => return err(Error::message("Article has no revision")),
+            };
+
+            if record.meta().revision() != Some(revision) {
+                return err(Error::conflict(current.clone()));
+            }
+
+            record.meta_mut().set_revision(revision + 1);
+            articles.insert(record.0, record.clone());
+
+            ok(record)
+        }
+    }
+
+    impl Default for Articles {
+        fn default() -> Self {
+            let mut map = HashMap::new();
+            let meta = Meta::new().with_id("1").with_revision(1);
+            map.insert(1, Record::new(Article(1, "Draft")).with_meta(meta));
+            Self(RefCell::new(map))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_articles_save() -> Result<(), Error<Article>> {
+        let store = Articles::default();
+        let read = store.0.borrow()[&1].clone();
+
+        let mut first = read.clone();
+        first.1 = "First";
+
+        let mut second = read;
+        second.1 = "Second";
+
+        let saved = store.save(first).await?;
+
+        assert_eq!(saved.1, "First");
+        assert_eq!(saved.meta().revision(), Some(2));
+
+        let error = store.save(second).await.unwrap_err();
+
+        assert!(error.is_conflict());
+        assert_eq!(error.kind(), ErrorKind::Conflict);
+        assert_eq!(
+            error.to_string(),
+            "Revision conflict, current revision is 2"
+        );
+        assert_eq!(error.into_current(), Some(saved));
+
+        Ok(())
+    }
+}
@@ -1,17 +1,18 @@
-use std::error;
-use std::fmt::{self, Display};
 use std::future::Future;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
 use brace_util_future::result::FutureResult;
 
+use crate::query::options::Options;
 use crate::record::Records;
 
+pub use crate::error::Error;
+
 pub trait Select<'a, T> {
     type Output: Future<Output = Result<Records<'a, T>, Error>>;
 
-    fn execute(&'a self) -> Self::Output;
+    fn execute(&'a self, options: Options) -> Self::Output;
 }
 
 pub struct FutureSelect<'a, T>(Pin<Box<FutureResult<'a, Records<'a, T>, Error>>>);
@@ -35,27 +36,3 @@ where
         self.0.as_mut().poll(cx)
     }
 }
-
-#[derive(Debug)]
-pub enum Error {
-    Message(String),
-}
-
-impl Error {
-    pub fn message<T>(message: T) -> Self
-    where
-        T: Into<String>,
-    {
-        Self::Message(message.into())
-    }
-}
-
-impl Display for Error {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            Self::Message(message) => message.fmt(f),
-        }
-    }
-}
-
-impl error::Error for Error {}
@@ -0,0 +1,59 @@
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use brace_util_future::result::FutureResult;
+
+use crate::query::predicate::Predicate;
+use crate::record::Records;
+
+pub use crate::error::Error;
+
+pub trait Update<'a, T, P, M>
+where
+    P: Predicate<T>,
+    M: Modify<T>,
+{
+    type Output: Future<Output = Result<Records<'a, T>, Error>>;
+
+    fn execute(&'a self, predicate: P, modify: M) -> Self::Output;
+}
+
+pub struct FutureUpdate<'a, T, P, M>(
+    Pin<Box<FutureResult<'a, Records<'a, T>, Error>>>,
+    PhantomData<&'a (P, M)>,
+);
+
+impl<'a, T, P, M> FutureUpdate<'a, T, P, M> {
+    pub fn new<F>(future: F) -> Self
+    where
+        F: Future<Output = Result<Records<'a, T>, Error>> + 'a,
+    {
+        Self(Box::pin(FutureResult::from_future(future)), PhantomData)
+    }
+}
+
+impl<'a, T, P, M> Future for FutureUpdate<'a, T, P, M>
+where
+    T: 'a,
+{
+    type Output = Result<Records<'a, T>, Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.as_mut().poll(cx)
+    }
+}
+
+pub trait Modify<T> {
+    fn modify(&self, item: &mut T);
+}
+
+impl<T, U> Modify<T> for U
+where
+    U: Fn(&mut T),
+{
+    fn modify(&self, item: &mut T) {
+        (self)(item)
+    }
+}
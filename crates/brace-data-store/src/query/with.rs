@@ -0,0 +1,241 @@
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use brace_util_future::result::FutureResult;
+
+use crate::query::options::Options;
+use crate::query::predicate::Predicate;
+use crate::record::Records;
+
+pub use crate::error::Error;
+
+pub trait With<'a, T, P>
+where
+    P: Predicate<T>,
+{
+    type Output: Future<Output = Result<Records<'a, T>, Error>>;
+
+    fn execute(
+        &'a self,
+        relations: Vec<String>,
+        predicate: Option<P>,
+        options: Options,
+    ) -> Self::Output;
+}
+
+pub struct FutureWith<'a, T, P>(
+    Pin<Box<FutureResult<'a, Records<'a, T>, Error>>>,
+    PhantomData<&'a (T, P)>,
+);
+
+impl<'a, T, P> FutureWith<'a, T, P> {
+    pub fn new<F>(future: F) -> Self
+    where
+        F: Future<Output = Result<Records<'a, T>, Error>> + 'a,
+    {
+        Self(Box::pin(FutureResult::from_future(future)), PhantomData)
+    }
+}
+
+impl<'a, T, P> Future for FutureWith<'a, T, P> {
+    type Output = Result<Records<'a, T>, Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use futures::future::{err, ok, Ready};
+    use futures::stream::{iter, StreamExt};
+
+    use crate::field::{Fields, Value};
+    use crate::query::options::Options;
+    use crate::query::predicate::Predicate;
+    use crate::query::project::Row;
+    use crate::record::{Record, Records};
+    use crate::relation::{relate, resolve, Pivot, Related, Relation};
+    use crate::Store;
+
+    use super::{Error, With};
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Article(i64, i64, &'static str);
+
+    impl Fields for Article {
+        fn field(&self, name: &str) -> Option<Value> {
+            match name {
+                "id" => Some(self.0.into()),
+                "author_id" => Some(self.1.into()),
+                "title" => Some(self.2.into()),
+                _ => None,
+            }
+        }
+    }
+
+    impl Related for Article {
+        fn relations() -> Vec<Relation> {
+            vec![
+                Relation::belongs_to("author", "authors", "author_id"),
+                Relation::has_many("comments", "comments", "article_id"),
+                Relation::many_to_many(
+                    "tags",
+                    "tags",
+                    Pivot::new("article_tags", "article_id", "tag_id"),
+                ),
+            ]
+        }
+    }
+
+    fn row(values: Vec<(&str, Value)>) -> Row {
+        values.into_iter().collect()
+    }
+
+    struct Articles {
+        articles: Vec<Article>,
+        tables: HashMap<String, Vec<Row>>,
+    }
+
+    impl Store for Articles {
+        type Item = Article;
+    }
+
+    impl<'a, P> With<'a, Article, P> for Articles
+    where
+        P: Predicate<Article>,
+    {
+        type Output = Ready<Result<Records<'a, Article>, Error>>;
+
+        fn execute(
+            &'a self,
+            relations: Vec<String>,
+            predicate: Option<P>,
+            options: Options,
+        ) -> Self::Output {
+            let articles = self
+                .articles
+                .iter()
+                .filter(|item| match &predicate {
+                    Some(predicate) => predicate.test(item),
+                    None => true,
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+            let mut records = options
+                .apply(articles)
+                .into_iter()
+                .map(Record::new)
+                .collect::<Vec<_>>();
+            let relations = match resolve::<Article>(&relations) {
+                Ok(relations) => relations,
+                Err(error) => return err(error),
+            };
+
+            match relate(&mut records, &relations, &self.tables) {
+                Ok(()) => ok(Records::from_stream(iter(records))),
+                Err(error) => err(error),
+            }
+        }
+    }
+
+    impl Default for Articles {
+        fn default() -> Self {
+            let mut tables = HashMap::new();
+
+            tables.insert(
+                "authors".to_owned(),
+                vec![
+                    row(vec![("id", 1.into()), ("name", "Mary".into())]),
+                    row(vec![("id", 2.into()), ("name", "Bram".into())]),
+                ],
+            );
+            tables.insert(
+                "comments".to_owned(),
+                vec![
+                    row(vec![("article_id", 1.into()), ("body", "First".into())]),
+                    row(vec![("article_id", 1.into()), ("body", "Second".into())]),
+                ],
+            );
+            tables.insert(
+                "tags".to_owned(),
+                vec![
+                    row(vec![("id", 1.into()), ("name", "gothic".into())]),
+                    row(vec![("id", 2.into()), ("name", "horror".into())]),
+                ],
+            );
+            tables.insert(
+                "article_tags".to_owned(),
+                vec![
+                    row(vec![("article_id", 1.into()), ("tag_id", 1.into())]),
+                    row(vec![("article_id", 1.into()), ("tag_id", 2.into())]),
+                    row(vec![("article_id", 2.into()), ("tag_id", 2.into())]),
+                ],
+            );
+
+            Self {
+                articles: vec![Article(1, 1, "Frankenstein"), Article(2, 2, "Dracula")],
+                tables,
+            }
+        }
+    }
+
+    fn names(rows: Option<&[Row]>, field: &str) -> Vec<Value> {
+        rows.unwrap_or_default()
+            .iter()
+            .filter_map(|row| row.field(field))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_articles_with() -> Result<(), Error> {
+        let store = Articles::default();
+        let mut articles = store.with(vec!["author", "comments", "tags"]).await?;
+
+        let article = articles.next().await.unwrap();
+
+        assert_eq!(*article, Article(1, 1, "Frankenstein"));
+        assert_eq!(
+            names(article.related("author"), "name"),
+            vec![Value::from("Mary")]
+        );
+        assert_eq!(
+            names(article.related("comments"), "body"),
+            vec![Value::from("First"), Value::from("Second")]
+        );
+        assert_eq!(
+            names(article.related("tags"), "name"),
+            vec![Value::from("gothic"), Value::from("horror")]
+        );
+
+        let article = articles.next().await.unwrap();
+
+        assert_eq!(
+            names(article.related("author"), "name"),
+            vec![Value::from("Bram")]
+        );
+        assert_eq!(
+            names(article.related("comments"), "body"),
+            Vec::<Value>::new()
+        );
+        assert_eq!(
+            names(article.related("tags"), "name"),
+            vec![Value::from("horror")]
+        );
+        assert_eq!(article.related("missing"), None);
+        assert!(articles.next().await.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_articles_with_unknown() {
+        let store = Articles::default();
+
+        assert!(store.with(vec!["editor"]).await.is_err());
+    }
+}
@@ -1,18 +1,70 @@
+use std::collections::BTreeMap;
 use std::future::Future;
 use std::ops::{Deref, DerefMut};
 use std::pin::Pin;
 use std::task::{Context, Poll};
+use std::time::SystemTime;
 
 use futures::stream::Stream;
 
 use brace_util_future::stream::FutureStream;
 
+use crate::query::project::Row;
+
 #[derive(Clone, Debug, PartialEq)]
-pub struct Record<T>(T);
+pub struct Record<T> {
+    data: T,
+    meta: Meta,
+    related: BTreeMap<String, Vec<Row>>,
+}
 
 impl<T> Record<T> {
     pub fn new(record: T) -> Self {
-        Self(record)
+        Self {
+            data: record,
+            meta: Meta::default(),
+            related: BTreeMap::new(),
+        }
+    }
+
+    pub fn meta(&self) -> &Meta {
+        &self.meta
+    }
+
+    pub fn meta_mut(&mut self) -> &mut Meta {
+        &mut self.meta
+    }
+
+    pub fn with_meta(mut self, meta: Meta) -> Self {
+        self.meta = meta;
+        self
+    }
+
+    pub fn related(&self, name: &str) -> Option<&[Row]> {
+        self.related.get(name).map(Vec::as_slice)
+    }
+
+    pub fn set_related<U>(&mut self, name: U, rows: Vec<Row>)
+    where
+        U: Into<String>,
+    {
+        self.related.insert(name.into(), rows);
+    }
+
+    pub fn with_related<U>(mut self, name: U, rows: Vec<Row>) -> Self
+    where
+        U: Into<String>,
+    {
+        self.set_related(name, rows);
+        self
+    }
+
+    pub fn into_inner(self) -> T {
+        self.data
+    }
+
+    pub fn into_parts(self) -> (T, Meta) {
+        (self.data, self.meta)
     }
 }
 
@@ -20,13 +72,93 @@ impl<T> Deref for Record<T> {
     type Target = T;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.data
     }
 }
 
 impl<T> DerefMut for Record<T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+        &mut self.data
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Meta {
+    id: Option<String>,
+    revision: Option<u64>,
+    created_at: Option<SystemTime>,
+    updated_at: Option<SystemTime>,
+}
+
+impl Meta {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    pub fn set_id<T>(&mut self, id: T)
+    where
+        T: Into<String>,
+    {
+        self.id = Some(id.into());
+    }
+
+    pub fn with_id<T>(mut self, id: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.set_id(id);
+        self
+    }
+
+    pub fn revision(&self) -> Option<u64> {
+        self.revision
+    }
+
+    pub fn set_revision(&mut self, revision: u64) {
+        self.revision = Some(revision);
+    }
+
+    pub fn with_revision(mut self, revision: u64) -> Self {
+        self.set_revision(revision);
+        self
+    }
+
+    pub fn created_at(&self) -> Option<SystemTime> {
+        self.created_at
+    }
+
+    pub fn set_created_at(&mut self, created_at: SystemTime) {
+        self.created_at = Some(created_at);
+    }
+
+    pub fn with_created_at(mut self, created_at: SystemTime) -> Self {
+        self.set_created_at(created_at);
+        self
+    }
+
+    pub fn updated_at(&self) -> Option<SystemTime> {
+        self.updated_at
+    }
+
+    pub fn set_updated_at(&mut self, updated_at: SystemTime) {
+        self.updated_at = Some(updated_at);
+    }
+
+    pub fn with_updated_at(mut self, updated_at: SystemTime) -> Self {
+        self.set_updated_at(updated_at);
+        self
+    }
+
+    pub fn etag(&self) -> Option<String> {
+        match (&self.id, self.revision) {
+            (Some(id), Some(revision)) => Some(format!("\"{}-{}\"", id, revision)),
+            (None, Some(revision)) => Some(format!("\"{}\"", revision)),
+            _ => None,
+        }
     }
 }
 
@@ -60,3 +192,47 @@ where
         self.0.as_mut().poll_next(cx)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::SystemTime;
+
+    use super::{Meta, Record};
+
+    #[test]
+    fn test_record_meta() {
+        let now = SystemTime::now();
+        let mut record = Record::new("Frankenstein").with_meta(
+            Meta::new()
+                .with_id("frankenstein")
+                .with_revision(1)
+                .with_created_at(now),
+        );
+
+        assert_eq!(*record, "Frankenstein");
+        assert_eq!(record.meta().id(), Some("frankenstein"));
+        assert_eq!(record.meta().revision(), Some(1));
+        assert_eq!(record.meta().created_at(), Some(now));
+        assert_eq!(record.meta().updated_at(), None);
+
+        record.meta_mut().set_revision(2);
+        record.meta_mut().set_updated_at(now);
+
+        assert_eq!(record.meta().revision(), Some(2));
+        assert_eq!(record.meta().updated_at(), Some(now));
+    }
+
+    #[test]
+    fn test_record_etag() {
+        assert_eq!(Meta::new().etag(), None);
+        assert_eq!(Meta::new().with_id("a").etag(), None);
+        assert_eq!(
+            Meta::new().with_revision(3).etag(),
+            Some("\"3\"".to_owned())
+        );
+        assert_eq!(
+            Meta::new().with_id("a").with_revision(3).etag(),
+            Some("\"a-3\"".to_owned())
+        );
+    }
+}
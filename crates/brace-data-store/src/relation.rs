@@ -0,0 +1,340 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::error::Error;
+use crate::field::{Fields, Value};
+use crate::query::expr::{field, Expr};
+use crate::query::project::Row;
+use crate::record::Record;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    HasMany,
+    BelongsTo,
+    ManyToMany,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pivot {
+    table: String,
+    local: String,
+    foreign: String,
+}
+
+impl Pivot {
+    pub fn new<T, U, V>(table: T, local: U, foreign: V) -> Self
+    where
+        T: Into<String>,
+        U: Into<String>,
+        V: Into<String>,
+    {
+        Self {
+            table: table.into(),
+            local: local.into(),
+            foreign: foreign.into(),
+        }
+    }
+
+    pub fn table(&self) -> &str {
+        &self.table
+    }
+
+    pub fn local(&self) -> &str {
+        &self.local
+    }
+
+    pub fn foreign(&self) -> &str {
+        &self.foreign
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Relation {
+    name: String,
+    kind: Kind,
+    target: String,
+    local: String,
+    foreign: String,
+    pivot: Option<Pivot>,
+}
+
+impl Relation {
+    pub fn has_many<T, U, V>(name: T, target: U, foreign: V) -> Self
+    where
+        T: Into<String>,
+        U: Into<String>,
+        V: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            kind: Kind::HasMany,
+            target: target.into(),
+            local: "id".to_owned(),
+            foreign: foreign.into(),
+            pivot: None,
+        }
+    }
+
+    pub fn belongs_to<T, U, V>(name: T, target: U, local: V) -> Self
+    where
+        T: Into<String>,
+        U: Into<String>,
+        V: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            kind: Kind::BelongsTo,
+            target: target.into(),
+            local: local.into(),
+            foreign: "id".to_owned(),
+            pivot: None,
+        }
+    }
+
+    pub fn many_to_many<T, U>(name: T, target: U, pivot: Pivot) -> Self
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            kind: Kind::ManyToMany,
+            target: target.into(),
+            local: "id".to_owned(),
+            foreign: "id".to_owned(),
+            pivot: Some(pivot),
+        }
+    }
+
+    pub fn with_local<T>(mut self, local: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.local = local.into();
+        self
+    }
+
+    pub fn with_foreign<T>(mut self, foreign: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.foreign = foreign.into();
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    pub fn local(&self) -> &str {
+        &self.local
+    }
+
+    pub fn foreign(&self) -> &str {
+        &self.foreign
+    }
+
+    pub fn pivot(&self) -> Option<&Pivot> {
+        self.pivot.as_ref()
+    }
+
+    pub fn lookup<'b, T, I>(&self, parents: I) -> Expr
+    where
+        T: Fields + 'b,
+        I: IntoIterator<Item = &'b T>,
+    {
+        let keys = unique(parents.into_iter().map(|parent| parent.field(&self.local)));
+
+        match &self.pivot {
+            Some(pivot) => field(pivot.local.as_str()).is_in(keys),
+            None => field(self.foreign.as_str()).is_in(keys),
+        }
+    }
+
+    pub fn lookup_through<'b, I>(&self, pivots: I) -> Expr
+    where
+        I: IntoIterator<Item = &'b Row>,
+    {
+        let keys = match &self.pivot {
+            Some(pivot) => unique(pivots.into_iter().map(|row| row.field(&pivot.foreign))),
+            None => Vec::new(),
+        };
+
+        field(self.foreign.as_str()).is_in(keys)
+    }
+
+    pub fn group<'b, U, P>(&self, targets: &'b [U], pivots: &[P]) -> BTreeMap<Value, Vec<&'b U>>
+    where
+        U: Fields,
+        P: Fields,
+    {
+        let mut groups = BTreeMap::new();
+
+        for target in targets {
+            if let Some(key) = target.field(&self.foreign) {
+                groups.entry(key).or_insert_with(Vec::new).push(target);
+            }
+        }
+
+        let pivot = match &self.pivot {
+            Some(pivot) => pivot,
+            None => return groups,
+        };
+
+        let mut through = BTreeMap::new();
+
+        for row in pivots {
+            if let (Some(local), Some(foreign)) =
+                (row.field(&pivot.local), row.field(&pivot.foreign))
+            {
+                if let Some(targets) = groups.get(&foreign) {
+                    through
+                        .entry(local)
+                        .or_insert_with(Vec::new)
+                        .extend(targets.iter().copied());
+                }
+            }
+        }
+
+        through
+    }
+
+    pub fn load<'b, T, U>(&self, parent: &T, groups: &BTreeMap<Value, Vec<&'b U>>) -> Vec<&'b U>
+    where
+        T: Fields,
+    {
+        parent
+            .field(&self.local)
+            .and_then(|key| groups.get(&key))
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+pub trait Related {
+    fn relations() -> Vec<Relation>;
+
+    fn relation(name: &str) -> Option<Relation> {
+        Self::relations()
+            .into_iter()
+            .find(|relation| relation.name() == name)
+    }
+}
+
+pub trait Tables {
+    fn rows(&self, table: &str, lookup: &Expr) -> Result<Vec<Row>, Error>;
+}
+
+impl<F> Tables for F
+where
+    F: Fn(&str, &Expr) -> Result<Vec<Row>, Error>,
+{
+    fn rows(&self, table: &str, lookup: &Expr) -> Result<Vec<Row>, Error> {
+        self(table, lookup)
+    }
+}
+
+impl Tables for HashMap<String, Vec<Row>> {
+    fn rows(&self, table: &str, lookup: &Expr) -> Result<Vec<Row>, Error> {
+        let rows = self
+            .get(table)
+            .ok_or_else(|| Error::not_found(format!("Unknown table `{}`", table)))?;
+
+        Ok(rows
+            .iter()
+            .filter(|row| lookup.evaluate(*row))
+            .cloned()
+            .collect())
+    }
+}
+
+pub fn resolve<T>(names: &[String]) -> Result<Vec<Relation>, Error>
+where
+    T: Related,
+{
+    names
+        .iter()
+        .map(|name| {
+            T::relation(name)
+                .ok_or_else(|| Error::not_found(format!("Unknown relation `{}`", name)))
+        })
+        .collect()
+}
+
+pub fn relate<T, S>(
+    records: &mut [Record<T>],
+    relations: &[Relation],
+    tables: &S,
+) -> Result<(), Error>
+where
+    T: Fields,
+    S: Tables + ?Sized,
+{
+    for relation in relations {
+        let lookup = relation.lookup(records.iter().map(|record| &**record));
+        let (targets, pivots) = match relation.pivot() {
+            Some(pivot) => {
+                let pivots = tables.rows(pivot.table(), &lookup)?;
+                let lookup = relation.lookup_through(&pivots);
+
+                (tables.rows(relation.target(), &lookup)?, pivots)
+            }
+            None => (tables.rows(relation.target(), &lookup)?, Vec::new()),
+        };
+        let groups = relation.group(&targets, &pivots);
+
+        for record in records.iter_mut() {
+            let rows = relation
+                .load(&**record, &groups)
+                .into_iter()
+                .cloned()
+                .collect();
+
+            record.set_related(relation.name(), rows);
+        }
+    }
+
+    Ok(())
+}
+
+fn unique<I>(values: I) -> Vec<Value>
+where
+    I: IntoIterator<Item = Option<Value>>,
+{
+    values
+        .into_iter()
+        .flatten()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::field::Value;
+    use crate::query::expr::field;
+    use crate::query::project::Row;
+
+    use super::Relation;
+
+    fn row(author: i64) -> Row {
+        vec![("author_id", Value::from(author))]
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn test_relation_lookup_unique() {
+        let relation = Relation::belongs_to("author", "authors", "author_id");
+        let rows = vec![row(3), row(1), row(3), row(2), row(1)];
+
+        assert_eq!(relation.lookup(&rows), field("id").is_in(vec![1, 2, 3]));
+    }
+}
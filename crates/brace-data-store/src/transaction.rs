@@ -0,0 +1,67 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use brace_util_future::result::FutureResult;
+
+use crate::Store;
+
+pub use crate::error::Error;
+
+pub trait Begin<'a> {
+    type Transaction: Transaction + 'a;
+    type Output: Future<Output = Result<Self::Transaction, Error>>;
+
+    fn execute(&'a self, isolation: Isolation) -> Self::Output;
+}
+
+pub trait Transaction: Store + Sized {
+    fn commit<'a>(self) -> FutureTransaction<'a, ()>
+    where
+        Self: 'a;
+
+    fn rollback<'a>(self) -> FutureTransaction<'a, ()>
+    where
+        Self: 'a;
+
+    fn savepoint(&mut self, name: &str) -> FutureTransaction<'_, ()>;
+
+    fn rollback_to(&mut self, name: &str) -> FutureTransaction<'_, ()>;
+
+    fn release(&mut self, name: &str) -> FutureTransaction<'_, ()>;
+}
+
+pub struct FutureTransaction<'a, T>(Pin<Box<FutureResult<'a, T, Error>>>);
+
+impl<'a, T> FutureTransaction<'a, T> {
+    pub fn new<F>(future: F) -> Self
+    where
+        F: Future<Output = Result<T, Error>> + 'a,
+    {
+        Self(Box::pin(FutureResult::from_future(future)))
+    }
+
+    pub fn ready(ok: T) -> Self {
+        Self(Box::pin(FutureResult::from_ok(ok)))
+    }
+
+    pub fn error(err: Error) -> Self {
+        Self(Box::pin(FutureResult::from_err(err)))
+    }
+}
+
+impl<'a, T> Future for FutureTransaction<'a, T> {
+    type Output = Result<T, Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.as_mut().poll(cx)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Isolation {
+    ReadUncommitted,
+    ReadCommitted,
+    RepeatableRead,
+    Serializable,
+}
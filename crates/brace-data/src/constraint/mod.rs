@@ -1,10 +1,11 @@
 use std::any::TypeId;
-use std::collections::hash_map::{HashMap, Values, ValuesMut};
+use std::collections::hash_map::{HashMap, Iter, Values, ValuesMut};
 use std::fmt::{self, Debug};
 use std::vec::IntoIter;
 
 use dyn_clone::{clone_trait_object, DynClone};
 
+use crate::schema::Object;
 use crate::util::DynPartialEq;
 use crate::{Data, Definition};
 
@@ -18,6 +19,8 @@ where
     T: Data,
 {
     fn constrain(&self, data: &T) -> Result<(), Error>;
+
+    fn describe(&self, _: &mut Object) {}
 }
 
 impl<T, U> Constrain<U> for T
@@ -28,10 +31,16 @@ where
     fn constrain(&self, data: &U) -> Result<(), Error> {
         Constraint::constrain(self.constraints(), data)
     }
+
+    fn describe(&self, schema: &mut Object) {
+        Constrain::describe(self.constraints(), schema)
+    }
 }
 
 pub trait Validate<T> {
     fn validate(&self, constraint: &T) -> Result<(), Error>;
+
+    fn describe(_: &T, _: &mut Object) {}
 }
 
 impl<T, U> Validate<U> for T
@@ -42,6 +51,10 @@ where
     fn validate(&self, constraint: &U) -> Result<(), Error> {
         constraint.constrain(self)
     }
+
+    fn describe(constraint: &U, schema: &mut Object) {
+        constraint.describe(schema)
+    }
 }
 
 pub trait Constraint<T>: Debug + DynClone + DynPartialEq
@@ -49,6 +62,8 @@ where
     T: Data,
 {
     fn constrain(&self, data: &T) -> Result<(), Error>;
+
+    fn describe(&self, _: &mut Object) {}
 }
 
 clone_trait_object!(<T> Constraint<T>);
@@ -61,6 +76,10 @@ where
     fn constrain(&self, data: &U) -> Result<(), Error> {
         data.validate(self)
     }
+
+    fn describe(&self, schema: &mut Object) {
+        U::describe(self, schema)
+    }
 }
 
 pub struct Constraints<T>(HashMap<TypeId, Box<dyn Constraint<T>>>)
@@ -88,6 +107,38 @@ where
     {
         self.0.remove(&TypeId::of::<U>());
     }
+
+    pub fn get<U>(&self) -> Option<&U>
+    where
+        U: Constraint<T> + 'static,
+    {
+        self.0
+            .get(&TypeId::of::<U>())
+            .and_then(|constraint| (**constraint).as_any().downcast_ref())
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub(crate) fn iter(&self) -> Iter<'_, TypeId, Box<dyn Constraint<T>>> {
+        self.0.iter()
+    }
+
+    pub(crate) fn get_dyn(&self, id: &TypeId) -> Option<&dyn Constraint<T>> {
+        self.0.get(id).map(|constraint| constraint.as_ref())
+    }
+
+    pub(crate) fn insert_boxed(
+        &mut self,
+        constraint: Box<dyn Constraint<T>>,
+    ) -> Option<Box<dyn Constraint<T>>> {
+        self.0.insert((*constraint).as_any().type_id(), constraint)
+    }
 }
 
 impl<T> Clone for Constraints<T>
@@ -145,6 +196,12 @@ where
 
         Ok(())
     }
+
+    fn describe(&self, schema: &mut Object) {
+        for constraint in self.0.values() {
+            (**constraint).describe(schema);
+        }
+    }
 }
 
 impl<T> IntoIterator for Constraints<T>
@@ -353,12 +410,30 @@ mod tests {
 
         assert_eq!((&constraints).into_iter().len(), 2);
 
+        assert_eq!(constraints.get::<ConstraintOne>(), Some(&ConstraintOne(1)));
+        assert_eq!(constraints.get::<ConstraintTwo>(), Some(&ConstraintTwo(2)));
+
         constraints.remove::<ConstraintOne>();
 
         assert_eq!((&constraints).into_iter().len(), 1);
+        assert_eq!(constraints.get::<ConstraintOne>(), None);
 
         constraints.remove::<ConstraintTwo>();
 
         assert_eq!((&constraints).into_iter().len(), 0);
     }
+
+    #[test]
+    fn test_definition_version() {
+        let mut definition = NumberDefinition::default();
+
+        assert!(!definition.set_version(2));
+        assert_eq!(definition.version(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "definition does not support versions")]
+    fn test_definition_with_version() {
+        NumberDefinition::default().with_version(2);
+    }
 }
@@ -1,8 +1,11 @@
+use serde_json::Value;
+
 use crate::constraint::{Constrain, Constraint, Constraints, Error};
+use crate::schema::Object;
 use crate::Data;
 
 #[derive(Clone, Debug, Default, PartialEq)]
-pub struct And<T>(Constraints<T>)
+pub struct And<T>(Vec<Constraints<T>>)
 where
     T: Data;
 
@@ -11,21 +14,37 @@ where
     T: Data,
 {
     pub fn new() -> Self {
-        Self(Constraints::new())
+        Self(Vec::new())
     }
 
     pub fn insert<U>(&mut self, constraint: U)
     where
         U: Constraint<T> + 'static,
     {
-        self.0.insert(constraint);
+        let mut branch = Constraints::new();
+
+        branch.insert(constraint);
+
+        self.0.push(branch);
+    }
+
+    pub fn push(&mut self, branch: Constraints<T>) {
+        self.0.push(branch);
     }
 
     pub fn remove<U>(&mut self)
     where
         U: Constraint<T> + 'static,
     {
-        self.0.remove::<U>()
+        for branch in self.0.iter_mut() {
+            branch.remove::<U>();
+        }
+
+        self.0.retain(|branch| !branch.is_empty());
+    }
+
+    pub fn branches(&self) -> &[Constraints<T>] {
+        &self.0
     }
 }
 
@@ -34,21 +53,64 @@ where
     T: Data,
 {
     fn constrain(&self, data: &T) -> Result<(), Error> {
-        for constraint in &self.0 {
-            (**constraint).constrain(data)?;
+        for branch in &self.0 {
+            Constrain::constrain(branch, data)?;
         }
 
         Ok(())
     }
+
+    fn describe(&self, schema: &mut Object) {
+        let mut schemas = Vec::new();
+
+        for branch in &self.0 {
+            let mut schema = Object::new();
+
+            Constrain::describe(branch, &mut schema);
+            schemas.push(schema.into());
+        }
+
+        schema.insert(String::from("allOf"), Value::Array(schemas));
+    }
+}
+
+impl<T> From<Constraints<T>> for And<T>
+where
+    T: Data,
+{
+    fn from(from: Constraints<T>) -> Self {
+        Self(
+            from.into_iter()
+                .map(|constraint| {
+                    let mut branch = Constraints::new();
+
+                    branch.insert_boxed(constraint);
+                    branch
+                })
+                .collect(),
+        )
+    }
+}
+
+impl<T> From<Vec<Constraints<T>>> for And<T>
+where
+    T: Data,
+{
+    fn from(from: Vec<Constraints<T>>) -> Self {
+        Self(from)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use serde_json::json;
+
     use super::And;
     use crate::constraint::types::max_length::MaxLength;
     use crate::constraint::types::min_length::MinLength;
-    use crate::constraint::Validate;
+    use crate::constraint::{Constrain, Validate};
     use crate::data::types::text::Text;
+    use crate::schema::Object;
 
     #[test]
     fn test_and() {
@@ -68,4 +130,16 @@ mod tests {
 
         assert!(text.validate(&constraint_two).is_err());
     }
+
+    #[test]
+    fn test_and_describe() {
+        let mut schema = Object::new();
+        let mut constraint = And::<Text>::new();
+
+        constraint.insert(MinLength(1));
+
+        constraint.describe(&mut schema);
+
+        assert_eq!(schema.get("allOf"), Some(&json!([{ "minLength": 1 }])));
+    }
 }
@@ -0,0 +1,46 @@
+use crate::constraint::{Constrain, Error, Validate};
+use crate::data::types::dynamic::{Dynamic, DynamicDefinition, Value};
+use crate::schema::{Object, Schema};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Items(pub DynamicDefinition);
+
+impl Constrain<Dynamic> for Items {
+    fn constrain(&self, data: &Dynamic) -> Result<(), Error> {
+        if let Value::List(items) = data.value() {
+            for item in items {
+                item.validate(&self.0)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn describe(&self, schema: &mut Object) {
+        schema.insert(String::from("items"), self.0.schema().into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Items;
+    use crate::constraint::types::max_length::MaxLength;
+    use crate::constraint::Validate;
+    use crate::data::types::dynamic::Dynamic;
+    use crate::{Define, Definition};
+
+    #[test]
+    fn test_dynamic_items() {
+        let list = Dynamic::new(vec![Dynamic::new("hello"), Dynamic::new("world")]);
+
+        assert!(list
+            .validate(&Items(Dynamic::define().with_constraint(MaxLength(5))))
+            .is_ok());
+        assert!(list
+            .validate(&Items(Dynamic::define().with_constraint(MaxLength(4))))
+            .is_err());
+        assert!(Dynamic::new("hello world")
+            .validate(&Items(Dynamic::define().with_constraint(MaxLength(4))))
+            .is_ok());
+    }
+}
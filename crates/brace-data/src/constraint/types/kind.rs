@@ -0,0 +1,102 @@
+use crate::constraint::{Constrain, Error};
+use crate::data::definition::Definition;
+use crate::data::types::dynamic::{Dynamic, Value};
+use crate::data::Data;
+use crate::schema::Object;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    Null,
+    Bool,
+    Number,
+    Integer,
+    Text,
+    List,
+    Object,
+}
+
+impl Kind {
+    pub fn of(value: &Value) -> Self {
+        match value {
+            Value::Null => Kind::Null,
+            Value::Bool(_) => Kind::Bool,
+            Value::Number(_) => Kind::Number,
+            Value::Text(_) => Kind::Text,
+            Value::List(_) => Kind::List,
+            Value::Object(_) => Kind::Object,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Kind::Null => "null",
+            Kind::Bool => "boolean",
+            Kind::Number => "number",
+            Kind::Integer => "integer",
+            Kind::Text => "string",
+            Kind::List => "array",
+            Kind::Object => "object",
+        }
+    }
+
+    pub fn matches(&self, value: &Value) -> bool {
+        match (self, value) {
+            (Kind::Integer, Value::Number(number)) => number.fract() == 0.0,
+            (kind, value) => *kind == Kind::of(value),
+        }
+    }
+}
+
+impl Constrain<Dynamic> for Kind {
+    fn constrain(&self, data: &Dynamic) -> Result<(), Error> {
+        if !self.matches(data.value()) {
+            return Err(Error::message(format!(
+                "{} is not of type {}",
+                data.definition().label(),
+                self.as_str(),
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn describe(&self, schema: &mut Object) {
+        schema.insert(String::from("type"), self.as_str().into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::Kind;
+    use crate::constraint::{Constrain, Validate};
+    use crate::data::types::dynamic::{Dynamic, Value};
+    use crate::schema::Object;
+
+    #[test]
+    fn test_dynamic_kind() {
+        let text = Dynamic::new("hello");
+        let list = Dynamic::new(vec![text.clone()]);
+
+        assert!(text.validate(&Kind::Text).is_ok());
+        assert!(text.validate(&Kind::List).is_err());
+        assert!(list.validate(&Kind::List).is_ok());
+        assert!(list.validate(&Kind::Text).is_err());
+        assert!(Dynamic::new(1.0).validate(&Kind::Number).is_ok());
+        assert!(Dynamic::new(1.0).validate(&Kind::Integer).is_ok());
+        assert!(Dynamic::new(1.5).validate(&Kind::Integer).is_err());
+        assert!(Dynamic::new(true).validate(&Kind::Bool).is_ok());
+        assert!(Dynamic::new(Value::Null).validate(&Kind::Null).is_ok());
+        assert!(Dynamic::new(Value::Null).validate(&Kind::Object).is_err());
+    }
+
+    #[test]
+    fn test_kind_describe() {
+        let mut schema = Object::new();
+
+        Kind::List.describe(&mut schema);
+
+        assert_eq!(schema.get("type"), Some(&json!("array")));
+    }
+}
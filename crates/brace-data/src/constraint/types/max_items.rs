@@ -0,0 +1,97 @@
+use crate::constraint::{Constrain, Error};
+use crate::data::definition::Definition;
+use crate::data::types::dynamic::{Dynamic, Value};
+use crate::data::types::list::List;
+use crate::data::Data;
+use crate::schema::Object;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MaxItems(pub usize);
+
+impl<T> Constrain<List<T>> for MaxItems
+where
+    T: Data,
+{
+    fn constrain(&self, data: &List<T>) -> Result<(), Error> {
+        if data.len() > self.0 {
+            return Err(Error::message(format!(
+                "{} exceeds maximum items of {}",
+                data.definition().label(),
+                self.0
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn describe(&self, schema: &mut Object) {
+        schema.insert(String::from("maxItems"), self.0.into());
+    }
+}
+
+impl Constrain<Dynamic> for MaxItems {
+    fn constrain(&self, data: &Dynamic) -> Result<(), Error> {
+        let len = match data.value() {
+            Value::List(list) => list.len(),
+            _ => return Ok(()),
+        };
+
+        if len > self.0 {
+            return Err(Error::message(format!(
+                "{} exceeds maximum items of {}",
+                data.definition().label(),
+                self.0
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn describe(&self, schema: &mut Object) {
+        schema.insert(String::from("maxItems"), self.0.into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::MaxItems;
+    use crate::constraint::{Constrain, Validate};
+    use crate::data::types::dynamic::Dynamic;
+    use crate::data::types::list::List;
+    use crate::data::types::text::Text;
+    use crate::schema::Object;
+
+    #[test]
+    fn test_list_max_items() {
+        let mut list = List::<Text>::new();
+
+        assert!(list.validate(&MaxItems(0)).is_ok());
+
+        list.push(Text::new("hello"));
+        list.push(Text::new("world"));
+
+        assert!(list.validate(&MaxItems(1)).is_err());
+        assert!(list.validate(&MaxItems(2)).is_ok());
+    }
+
+    #[test]
+    fn test_max_items_describe() {
+        let mut list = Object::new();
+
+        Constrain::<List<Text>>::describe(&MaxItems(3), &mut list);
+
+        assert_eq!(list.get("maxItems"), Some(&json!(3)));
+    }
+
+    #[test]
+    fn test_dynamic_max_items() {
+        let text = Dynamic::new("hello");
+        let list = Dynamic::new(vec![Dynamic::new("hello"), Dynamic::new("world")]);
+
+        assert!(list.validate(&MaxItems(2)).is_ok());
+        assert!(list.validate(&MaxItems(1)).is_err());
+        assert!(text.validate(&MaxItems(1)).is_ok());
+    }
+}
@@ -1,8 +1,10 @@
 use crate::constraint::{Constrain, Error};
 use crate::data::definition::Definition;
+use crate::data::types::dynamic::{Dynamic, Value};
 use crate::data::types::list::List;
 use crate::data::types::text::Text;
 use crate::data::Data;
+use crate::schema::Object;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct MaxLength(pub usize);
@@ -19,6 +21,10 @@ impl Constrain<Text> for MaxLength {
 
         Ok(())
     }
+
+    fn describe(&self, schema: &mut Object) {
+        schema.insert(String::from("maxLength"), self.0.into());
+    }
 }
 
 impl<T> Constrain<List<T>> for MaxLength
@@ -36,14 +42,45 @@ where
 
         Ok(())
     }
+
+    fn describe(&self, schema: &mut Object) {
+        schema.insert(String::from("maxItems"), self.0.into());
+    }
+}
+
+impl Constrain<Dynamic> for MaxLength {
+    fn constrain(&self, data: &Dynamic) -> Result<(), Error> {
+        let len = match data.value() {
+            Value::Text(text) => text.len(),
+            _ => return Ok(()),
+        };
+
+        if len > self.0 {
+            return Err(Error::message(format!(
+                "{} exceeds maximum length of {}",
+                data.definition().label(),
+                self.0
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn describe(&self, schema: &mut Object) {
+        schema.insert(String::from("maxLength"), self.0.into());
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use serde_json::json;
+
     use super::MaxLength;
-    use crate::constraint::Validate;
+    use crate::constraint::{Constrain, Validate};
+    use crate::data::types::dynamic::Dynamic;
     use crate::data::types::list::List;
     use crate::data::types::text::Text;
+    use crate::schema::Object;
 
     #[test]
     fn test_text_max_length() {
@@ -73,4 +110,26 @@ mod tests {
         assert!(list.validate(&MaxLength(1)).is_err());
         assert!(list.validate(&MaxLength(2)).is_ok());
     }
+
+    #[test]
+    fn test_max_length_describe() {
+        let mut text = Object::new();
+        let mut list = Object::new();
+
+        Constrain::<Text>::describe(&MaxLength(3), &mut text);
+        Constrain::<List<Text>>::describe(&MaxLength(3), &mut list);
+
+        assert_eq!(text.get("maxLength"), Some(&json!(3)));
+        assert_eq!(list.get("maxItems"), Some(&json!(3)));
+    }
+
+    #[test]
+    fn test_dynamic_max_length() {
+        let text = Dynamic::new("hello");
+        let list = Dynamic::new(vec![Dynamic::new("hello"), Dynamic::new("world")]);
+
+        assert!(text.validate(&MaxLength(5)).is_ok());
+        assert!(text.validate(&MaxLength(4)).is_err());
+        assert!(list.validate(&MaxLength(1)).is_ok());
+    }
 }
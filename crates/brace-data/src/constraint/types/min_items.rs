@@ -0,0 +1,98 @@
+use crate::constraint::{Constrain, Error};
+use crate::data::definition::Definition;
+use crate::data::types::dynamic::{Dynamic, Value};
+use crate::data::types::list::List;
+use crate::data::Data;
+use crate::schema::Object;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MinItems(pub usize);
+
+impl<T> Constrain<List<T>> for MinItems
+where
+    T: Data,
+{
+    fn constrain(&self, data: &List<T>) -> Result<(), Error> {
+        if data.len() < self.0 {
+            return Err(Error::message(format!(
+                "{} does not meet minimum items of {}",
+                data.definition().label(),
+                self.0
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn describe(&self, schema: &mut Object) {
+        schema.insert(String::from("minItems"), self.0.into());
+    }
+}
+
+impl Constrain<Dynamic> for MinItems {
+    fn constrain(&self, data: &Dynamic) -> Result<(), Error> {
+        let len = match data.value() {
+            Value::List(list) => list.len(),
+            _ => return Ok(()),
+        };
+
+        if len < self.0 {
+            return Err(Error::message(format!(
+                "{} does not meet minimum items of {}",
+                data.definition().label(),
+                self.0
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn describe(&self, schema: &mut Object) {
+        schema.insert(String::from("minItems"), self.0.into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::MinItems;
+    use crate::constraint::{Constrain, Validate};
+    use crate::data::types::dynamic::Dynamic;
+    use crate::data::types::list::List;
+    use crate::data::types::text::Text;
+    use crate::schema::Object;
+
+    #[test]
+    fn test_list_min_items() {
+        let mut list = List::<Text>::new();
+
+        assert!(list.validate(&MinItems(0)).is_ok());
+        assert!(list.validate(&MinItems(1)).is_err());
+
+        list.push(Text::new("hello"));
+
+        assert!(list.validate(&MinItems(0)).is_ok());
+        assert!(list.validate(&MinItems(1)).is_ok());
+        assert!(list.validate(&MinItems(2)).is_err());
+    }
+
+    #[test]
+    fn test_min_items_describe() {
+        let mut list = Object::new();
+
+        Constrain::<List<Text>>::describe(&MinItems(3), &mut list);
+
+        assert_eq!(list.get("minItems"), Some(&json!(3)));
+    }
+
+    #[test]
+    fn test_dynamic_min_items() {
+        let text = Dynamic::new("hello");
+        let list = Dynamic::new(vec![Dynamic::new("hello"), Dynamic::new("world")]);
+
+        assert!(list.validate(&MinItems(2)).is_ok());
+        assert!(list.validate(&MinItems(3)).is_err());
+        assert!(text.validate(&MinItems(9)).is_ok());
+    }
+}
@@ -1,8 +1,10 @@
 use crate::constraint::{Constrain, Error};
 use crate::data::definition::Definition;
+use crate::data::types::dynamic::{Dynamic, Value};
 use crate::data::types::list::List;
 use crate::data::types::text::Text;
 use crate::data::Data;
+use crate::schema::Object;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct MinLength(pub usize);
@@ -19,6 +21,10 @@ impl Constrain<Text> for MinLength {
 
         Ok(())
     }
+
+    fn describe(&self, schema: &mut Object) {
+        schema.insert(String::from("minLength"), self.0.into());
+    }
 }
 
 impl<T> Constrain<List<T>> for MinLength
@@ -36,14 +42,45 @@ where
 
         Ok(())
     }
+
+    fn describe(&self, schema: &mut Object) {
+        schema.insert(String::from("minItems"), self.0.into());
+    }
+}
+
+impl Constrain<Dynamic> for MinLength {
+    fn constrain(&self, data: &Dynamic) -> Result<(), Error> {
+        let len = match data.value() {
+            Value::Text(text) => text.len(),
+            _ => return Ok(()),
+        };
+
+        if len < self.0 {
+            return Err(Error::message(format!(
+                "{} does not meet minimum length of {}",
+                data.definition().label(),
+                self.0
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn describe(&self, schema: &mut Object) {
+        schema.insert(String::from("minLength"), self.0.into());
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use serde_json::json;
+
     use super::MinLength;
-    use crate::constraint::Validate;
+    use crate::constraint::{Constrain, Validate};
+    use crate::data::types::dynamic::Dynamic;
     use crate::data::types::list::List;
     use crate::data::types::text::Text;
+    use crate::schema::Object;
 
     #[test]
     fn test_text_min_length() {
@@ -67,4 +104,26 @@ mod tests {
         assert!(list.validate(&MinLength(1)).is_ok());
         assert!(list.validate(&MinLength(2)).is_err());
     }
+
+    #[test]
+    fn test_min_length_describe() {
+        let mut text = Object::new();
+        let mut list = Object::new();
+
+        Constrain::<Text>::describe(&MinLength(3), &mut text);
+        Constrain::<List<Text>>::describe(&MinLength(3), &mut list);
+
+        assert_eq!(text.get("minLength"), Some(&json!(3)));
+        assert_eq!(list.get("minItems"), Some(&json!(3)));
+    }
+
+    #[test]
+    fn test_dynamic_min_length() {
+        let text = Dynamic::new("hello");
+        let list = Dynamic::new(vec![Dynamic::new("hello")]);
+
+        assert!(text.validate(&MinLength(5)).is_ok());
+        assert!(text.validate(&MinLength(6)).is_err());
+        assert!(list.validate(&MinLength(3)).is_ok());
+    }
 }
@@ -1,5 +1,11 @@
 pub mod and;
+pub mod items;
+pub mod kind;
+pub mod max_items;
 pub mod max_length;
+pub mod min_items;
 pub mod min_length;
 pub mod or;
 pub mod pattern;
+pub mod properties;
+pub mod required;
@@ -2,8 +2,10 @@ use regex::Regex;
 
 use crate::constraint::{Constrain, Error};
 use crate::data::definition::Definition;
+use crate::data::types::dynamic::{Dynamic, Value};
 use crate::data::types::text::Text;
 use crate::data::Data;
+use crate::schema::Object;
 
 #[derive(Clone, Debug)]
 pub struct Pattern(Regex);
@@ -35,13 +37,41 @@ impl Constrain<Text> for Pattern {
 
         Ok(())
     }
+
+    fn describe(&self, schema: &mut Object) {
+        schema.insert(String::from("pattern"), self.0.as_str().into());
+    }
+}
+
+impl Constrain<Dynamic> for Pattern {
+    fn constrain(&self, data: &Dynamic) -> Result<(), Error> {
+        if let Value::Text(text) = data.value() {
+            if !self.0.is_match(text) {
+                return Err(Error::message(format!(
+                    "{} does not match pattern {}",
+                    data.definition().label(),
+                    self.0.as_str(),
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn describe(&self, schema: &mut Object) {
+        schema.insert(String::from("pattern"), self.0.as_str().into());
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use serde_json::json;
+
     use super::Pattern;
-    use crate::constraint::Validate;
+    use crate::constraint::{Constrain, Validate};
+    use crate::data::types::dynamic::Dynamic;
     use crate::data::types::text::Text;
+    use crate::schema::Object;
 
     #[test]
     fn test_pattern_equality() {
@@ -63,4 +93,23 @@ mod tests {
         assert!(text.validate(&pattern).is_err());
         assert!(number.validate(&pattern).is_ok());
     }
+
+    #[test]
+    fn test_pattern_describe() {
+        let mut schema = Object::new();
+        let pattern = Pattern::new("[0-9]{3}").unwrap();
+
+        Constrain::<Text>::describe(&pattern, &mut schema);
+
+        assert_eq!(schema.get("pattern"), Some(&json!("[0-9]{3}")));
+    }
+
+    #[test]
+    fn test_dynamic_pattern() {
+        let pattern = Pattern::new("^[0-9]+$").unwrap();
+
+        assert!(Dynamic::new("123").validate(&pattern).is_ok());
+        assert!(Dynamic::new("abc").validate(&pattern).is_err());
+        assert!(Dynamic::new(vec![]).validate(&pattern).is_ok());
+    }
 }
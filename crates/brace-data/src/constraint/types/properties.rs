@@ -0,0 +1,112 @@
+use std::collections::btree_map::{BTreeMap, Iter};
+
+use serde_json::Value as Json;
+
+use crate::constraint::{Constrain, Error, Validate};
+use crate::data::types::dynamic::{Dynamic, DynamicDefinition, Value};
+use crate::schema::{Object, Schema};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Properties(BTreeMap<String, DynamicDefinition>);
+
+impl Properties {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert<T>(&mut self, name: T, definition: DynamicDefinition)
+    where
+        T: Into<String>,
+    {
+        self.0.insert(name.into(), definition);
+    }
+
+    pub fn remove(&mut self, name: &str) {
+        self.0.remove(name);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&DynamicDefinition> {
+        self.0.get(name)
+    }
+}
+
+impl Constrain<Dynamic> for Properties {
+    fn constrain(&self, data: &Dynamic) -> Result<(), Error> {
+        if let Value::Object(object) = data.value() {
+            for (name, definition) in &self.0 {
+                if let Some(value) = object.get(name) {
+                    value.validate(definition)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn describe(&self, schema: &mut Object) {
+        let properties = self
+            .0
+            .iter()
+            .map(|(name, definition)| (name.clone(), Json::Object(definition.schema())))
+            .collect();
+
+        schema.insert(String::from("properties"), Json::Object(properties));
+    }
+}
+
+impl<'a> IntoIterator for &'a Properties {
+    type Item = (&'a String, &'a DynamicDefinition);
+    type IntoIter = Iter<'a, String, DynamicDefinition>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use serde_json::json;
+
+    use super::Properties;
+    use crate::constraint::types::kind::Kind;
+    use crate::constraint::{Constrain, Validate};
+    use crate::data::types::dynamic::Dynamic;
+    use crate::schema::Object;
+    use crate::{Define, Definition};
+
+    #[test]
+    fn test_dynamic_properties() {
+        let mut properties = Properties::new();
+
+        properties.insert("title", Dynamic::define().with_constraint(Kind::Text));
+
+        let valid = Dynamic::try_from(json!({ "title": "Emma" })).unwrap();
+        let invalid = Dynamic::try_from(json!({ "title": 1 })).unwrap();
+        let missing = Dynamic::try_from(json!({})).unwrap();
+
+        assert!(valid.validate(&properties).is_ok());
+        assert!(invalid.validate(&properties).is_err());
+        assert!(missing.validate(&properties).is_ok());
+    }
+
+    #[test]
+    fn test_properties_describe() {
+        let mut schema = Object::new();
+        let mut properties = Properties::new();
+
+        properties.insert(
+            "title",
+            Dynamic::define()
+                .with_label("Title")
+                .with_constraint(Kind::Text),
+        );
+        properties.describe(&mut schema);
+
+        assert_eq!(
+            schema.get("properties"),
+            Some(&json!({ "title": { "type": "string", "title": "Title" } }))
+        );
+    }
+}
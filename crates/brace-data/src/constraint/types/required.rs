@@ -0,0 +1,53 @@
+use crate::constraint::{Constrain, Error};
+use crate::data::definition::Definition;
+use crate::data::types::dynamic::{Dynamic, Value};
+use crate::data::Data;
+use crate::schema::Object;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Required(pub Vec<String>);
+
+impl Constrain<Dynamic> for Required {
+    fn constrain(&self, data: &Dynamic) -> Result<(), Error> {
+        if let Value::Object(object) = data.value() {
+            for name in &self.0 {
+                if !object.contains_key(name) {
+                    return Err(Error::message(format!(
+                        "{} is missing required property {}",
+                        data.definition().label(),
+                        name,
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn describe(&self, schema: &mut Object) {
+        schema.insert(String::from("required"), self.0.clone().into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use serde_json::json;
+
+    use super::Required;
+    use crate::constraint::Validate;
+    use crate::data::types::dynamic::Dynamic;
+
+    #[test]
+    fn test_dynamic_required() {
+        let required = Required(vec![String::from("title")]);
+
+        let valid = Dynamic::try_from(json!({ "title": "Emma" })).unwrap();
+        let missing = Dynamic::try_from(json!({ "name": "Emma" })).unwrap();
+
+        assert!(valid.validate(&required).is_ok());
+        assert!(missing.validate(&required).is_err());
+        assert!(Dynamic::new("title").validate(&required).is_ok());
+    }
+}
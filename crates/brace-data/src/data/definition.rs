@@ -18,6 +18,24 @@ pub trait Definition: Sized {
         self
     }
 
+    fn version(&self) -> u32 {
+        0
+    }
+
+    // Definitions that do not track versions stay at version 0 and report
+    // that the version was not set.
+    fn set_version(&mut self, _version: u32) -> bool {
+        false
+    }
+
+    fn with_version(mut self, version: u32) -> Self {
+        assert!(
+            self.set_version(version),
+            "definition does not support versions"
+        );
+        self
+    }
+
     fn constraints(&self) -> &Constraints<Self::Data>;
 
     fn constraints_mut(&mut self) -> &mut Constraints<Self::Data>;
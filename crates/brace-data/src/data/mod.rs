@@ -1,6 +1,7 @@
 use self::definition::Definition;
 
 pub mod definition;
+pub mod registry;
 pub mod types;
 
 pub trait Data {
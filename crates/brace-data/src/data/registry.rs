@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+use std::fmt::{self, Debug};
+
+use crate::data::definition::Definition;
+use crate::data::types::dynamic::DynamicDefinition;
+use crate::Kind;
+
+type Factory = Box<dyn Fn() -> DynamicDefinition>;
+
+pub struct Registry(HashMap<String, Factory>);
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn empty() -> Self {
+        Self(HashMap::new())
+    }
+
+    pub fn register<T, F>(&mut self, name: T, factory: F)
+    where
+        T: Into<String>,
+        F: Fn() -> DynamicDefinition + 'static,
+    {
+        self.0.insert(name.into(), Box::new(factory));
+    }
+
+    pub fn with<T, F>(mut self, name: T, factory: F) -> Self
+    where
+        T: Into<String>,
+        F: Fn() -> DynamicDefinition + 'static,
+    {
+        self.register(name, factory);
+        self
+    }
+
+    pub fn unregister(&mut self, name: &str) {
+        self.0.remove(name);
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.0.contains_key(name)
+    }
+
+    pub fn create(&self, name: &str) -> Option<DynamicDefinition> {
+        self.0.get(name).map(|factory| factory())
+    }
+}
+
+impl Debug for Registry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.0.keys()).finish()
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        let kinds = [
+            ("null", "Null", Kind::Null),
+            ("bool", "Bool", Kind::Bool),
+            ("number", "Number", Kind::Number),
+            ("integer", "Integer", Kind::Integer),
+            ("text", "Text", Kind::Text),
+            ("list", "List", Kind::List),
+            ("object", "Object", Kind::Object),
+        ];
+
+        let mut registry = Self::empty();
+
+        for (name, label, kind) in kinds.iter().cloned() {
+            registry.register(name, move || {
+                DynamicDefinition::new()
+                    .with_label(label)
+                    .with_constraint(kind)
+            });
+        }
+
+        registry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use serde_json::json;
+
+    use super::Registry;
+    use crate::constraint::Validate;
+    use crate::{Definition, Dynamic, Kind, MinLength, Properties, Required};
+
+    #[test]
+    fn test_registry_defaults() {
+        let registry = Registry::new();
+
+        assert!(registry.contains("text"));
+        assert!(!Registry::empty().contains("text"));
+
+        let text = registry.create("text").unwrap();
+
+        assert_eq!(text.label(), "Text");
+        assert!(Dynamic::new("hello").validate(&text).is_ok());
+        assert!(Dynamic::new(1.0).validate(&text).is_err());
+        assert!(registry.create("article").is_none());
+    }
+
+    #[test]
+    fn test_registry_assemble() {
+        let mut registry = Registry::new();
+
+        registry.register("title", || {
+            Registry::new()
+                .create("text")
+                .unwrap()
+                .with_label("Title")
+                .with_constraint(MinLength(1))
+        });
+
+        let fields = [("title", "title"), ("pages", "integer")];
+        let mut properties = Properties::new();
+
+        for (name, kind) in fields.iter() {
+            properties.insert(*name, registry.create(kind).unwrap());
+        }
+
+        registry.register("book", move || {
+            Registry::new()
+                .create("object")
+                .unwrap()
+                .with_label("Book")
+                .with_constraint(properties.clone())
+                .with_constraint(Required(vec![String::from("title")]))
+        });
+
+        let book = registry.create("book").unwrap();
+
+        assert!(book.constraints().get::<Kind>().is_some());
+
+        let valid = Dynamic::try_from(json!({ "title": "Emma", "pages": 474 })).unwrap();
+        let invalid = Dynamic::try_from(json!({ "title": "", "pages": 474 })).unwrap();
+
+        assert!(valid.validate(&book).is_ok());
+        assert!(invalid.validate(&book).is_err());
+
+        registry.unregister("book");
+
+        assert!(!registry.contains("book"));
+    }
+}
@@ -0,0 +1,388 @@
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+use crate::constraint::{Constrain, Constraints};
+use crate::data::definition::Definition;
+use crate::data::{Construct, Data, Define};
+use crate::schema::{Error, Object, Schema};
+use crate::Kind;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dynamic(Value, DynamicDefinition);
+
+impl Dynamic {
+    pub fn new<T>(value: T) -> Self
+    where
+        T: Into<Value>,
+    {
+        Self(value.into(), DynamicDefinition::default())
+    }
+
+    pub fn value(&self) -> &Value {
+        &self.0
+    }
+
+    pub fn into_value(self) -> Value {
+        self.0
+    }
+}
+
+impl Data for Dynamic {
+    type Definition = DynamicDefinition;
+
+    fn definition(&self) -> &Self::Definition {
+        &self.1
+    }
+}
+
+impl Define for Dynamic {
+    fn define() -> Self::Definition {
+        Self::Definition::default()
+    }
+}
+
+impl Construct for Dynamic {
+    type Value = Value;
+
+    fn construct<T>(value: T, definition: Self::Definition) -> Self
+    where
+        T: Into<Self::Value>,
+    {
+        Self(value.into(), definition)
+    }
+}
+
+impl From<Value> for Dynamic {
+    fn from(from: Value) -> Self {
+        Self::new(from)
+    }
+}
+
+impl From<bool> for Dynamic {
+    fn from(from: bool) -> Self {
+        Self::new(from)
+    }
+}
+
+impl From<f64> for Dynamic {
+    fn from(from: f64) -> Self {
+        Self::new(from)
+    }
+}
+
+impl From<&str> for Dynamic {
+    fn from(from: &str) -> Self {
+        Self::new(from)
+    }
+}
+
+impl From<String> for Dynamic {
+    fn from(from: String) -> Self {
+        Self::new(from)
+    }
+}
+
+impl From<Vec<Dynamic>> for Dynamic {
+    fn from(from: Vec<Dynamic>) -> Self {
+        Self::new(from)
+    }
+}
+
+impl From<BTreeMap<String, Dynamic>> for Dynamic {
+    fn from(from: BTreeMap<String, Dynamic>) -> Self {
+        Self::new(from)
+    }
+}
+
+impl TryFrom<serde_json::Value> for Dynamic {
+    type Error = Error;
+
+    fn try_from(from: serde_json::Value) -> Result<Self, Self::Error> {
+        Ok(Self::new(Value::try_from(from)?))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    Text(String),
+    List(Vec<Dynamic>),
+    Object(BTreeMap<String, Dynamic>),
+}
+
+impl From<bool> for Value {
+    fn from(from: bool) -> Self {
+        Self::Bool(from)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(from: f64) -> Self {
+        Self::Number(from)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(from: &str) -> Self {
+        Self::Text(from.to_owned())
+    }
+}
+
+impl From<String> for Value {
+    fn from(from: String) -> Self {
+        Self::Text(from)
+    }
+}
+
+impl From<Vec<Dynamic>> for Value {
+    fn from(from: Vec<Dynamic>) -> Self {
+        Self::List(from)
+    }
+}
+
+impl From<BTreeMap<String, Dynamic>> for Value {
+    fn from(from: BTreeMap<String, Dynamic>) -> Self {
+        Self::Object(from)
+    }
+}
+
+impl TryFrom<serde_json::Value> for Value {
+    type Error = Error;
+
+    fn try_from(from: serde_json::Value) -> Result<Self, Self::Error> {
+        match from {
+            serde_json::Value::Null => Ok(Self::Null),
+            serde_json::Value::Bool(value) => Ok(Self::Bool(value)),
+            serde_json::Value::Number(number) => match number.as_f64() {
+                Some(number) => Ok(Self::Number(number)),
+                None => Err(Error::unsupported(format!("unsupported number {}", number))),
+            },
+            serde_json::Value::String(text) => Ok(Self::Text(text)),
+            serde_json::Value::Array(list) => Ok(Self::List(
+                list.into_iter()
+                    .map(Dynamic::try_from)
+                    .collect::<Result<_, _>>()?,
+            )),
+            serde_json::Value::Object(object) => Ok(Self::Object(
+                object
+                    .into_iter()
+                    .map(|(key, value)| Ok((key, Dynamic::try_from(value)?)))
+                    .collect::<Result<_, Error>>()?,
+            )),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DynamicDefinition {
+    label: String,
+    version: u32,
+    constraints: Constraints<Dynamic>,
+}
+
+impl DynamicDefinition {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Definition for DynamicDefinition {
+    type Data = Dynamic;
+
+    fn label(&self) -> &str {
+        &self.label
+    }
+
+    fn set_label<T>(&mut self, label: T)
+    where
+        T: Into<String>,
+    {
+        self.label = label.into();
+    }
+
+    fn version(&self) -> u32 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u32) -> bool {
+        self.version = version;
+        true
+    }
+
+    fn constraints(&self) -> &Constraints<Self::Data> {
+        &self.constraints
+    }
+
+    fn constraints_mut(&mut self) -> &mut Constraints<Self::Data> {
+        &mut self.constraints
+    }
+}
+
+impl Schema for DynamicDefinition {
+    fn schema(&self) -> Object {
+        let mut schema = Object::new();
+
+        if let Some(kind) = self.constraints.get::<Kind>() {
+            kind.describe(&mut schema);
+        }
+
+        schema.insert(String::from("title"), self.label.as_str().into());
+
+        self.constraints.describe(&mut schema);
+
+        schema
+    }
+}
+
+impl Default for DynamicDefinition {
+    fn default() -> Self {
+        Self {
+            label: String::from("Value"),
+            version: 1,
+            constraints: Constraints::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::convert::TryFrom;
+
+    use serde_json::json;
+
+    use super::{Dynamic, DynamicDefinition, Value};
+    use crate::constraint::types::items::Items;
+    use crate::constraint::types::kind::Kind;
+    use crate::constraint::types::max_items::MaxItems;
+    use crate::constraint::types::min_length::MinLength;
+    use crate::constraint::types::properties::Properties;
+    use crate::constraint::types::required::Required;
+    use crate::constraint::Validate;
+    use crate::schema::Schema;
+    use crate::{Construct, Data, Define, Definition};
+
+    #[test]
+    fn test_dynamic_from() {
+        let text = Dynamic::from("hello");
+        let list = Dynamic::from(vec![Dynamic::from("hello")]);
+
+        assert_eq!(text.value(), &Value::Text(String::from("hello")));
+        assert_eq!(list.into_value(), Value::List(vec![Dynamic::new("hello")]));
+    }
+
+    #[test]
+    fn test_dynamic_try_from_json() {
+        let list = Dynamic::try_from(json!(["one", "two"])).unwrap();
+
+        assert_eq!(
+            list,
+            Dynamic::new(vec![Dynamic::new("one"), Dynamic::new("two")])
+        );
+
+        let object = Dynamic::try_from(json!({
+            "title": "Nineteen Eighty-Four",
+            "pages": 328,
+            "draft": false,
+            "editor": null,
+        }))
+        .unwrap();
+
+        let mut map = BTreeMap::new();
+
+        map.insert(String::from("title"), Dynamic::new("Nineteen Eighty-Four"));
+        map.insert(String::from("pages"), Dynamic::new(328.0));
+        map.insert(String::from("draft"), Dynamic::new(false));
+        map.insert(String::from("editor"), Dynamic::new(Value::Null));
+
+        assert_eq!(object, Dynamic::new(map));
+    }
+
+    #[test]
+    fn test_dynamic_data_definition() {
+        let tags = Dynamic::construct(
+            vec![Dynamic::new("one"), Dynamic::new("two")],
+            Dynamic::define()
+                .with_label("Tags")
+                .with_constraint(Kind::List)
+                .with_constraint(MaxItems(2))
+                .with_constraint(Items(
+                    Dynamic::define()
+                        .with_constraint(Kind::Text)
+                        .with_constraint(MinLength(3)),
+                )),
+        );
+
+        assert!(tags.validate(tags.definition()).is_ok());
+        assert_eq!(tags.definition().label(), "Tags");
+
+        let mut definition = DynamicDefinition::new();
+
+        definition.constraints_mut().insert(Kind::Text);
+        definition.set_label("Tags");
+
+        assert!(tags.validate(&definition).is_err());
+        assert!(Dynamic::new("hi").validate(tags.definition()).is_err());
+    }
+
+    #[test]
+    fn test_dynamic_definition_schema() {
+        let definition = Dynamic::define()
+            .with_label("Tags")
+            .with_constraint(Kind::List)
+            .with_constraint(MaxItems(2))
+            .with_constraint(Items(Dynamic::define().with_constraint(MinLength(3))));
+
+        assert_eq!(
+            definition.schema(),
+            *json!({
+                "type": "array",
+                "title": "Tags",
+                "maxItems": 2,
+                "items": {
+                    "title": "Value",
+                    "minLength": 3,
+                },
+            })
+            .as_object()
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_dynamic_object() {
+        let mut properties = Properties::new();
+
+        properties.insert(
+            "title",
+            Dynamic::define()
+                .with_constraint(Kind::Text)
+                .with_constraint(MinLength(1)),
+        );
+        properties.insert("pages", Dynamic::define().with_constraint(Kind::Integer));
+
+        let definition = Dynamic::define()
+            .with_label("Book")
+            .with_constraint(Kind::Object)
+            .with_constraint(properties)
+            .with_constraint(Required(vec![String::from("title")]));
+
+        let book = Dynamic::try_from(json!({ "title": "Emma", "pages": 474 })).unwrap();
+
+        assert!(book.validate(&definition).is_ok());
+
+        let book = Dynamic::try_from(json!({ "title": "", "pages": 474 })).unwrap();
+
+        assert!(book.validate(&definition).is_err());
+
+        let book = Dynamic::try_from(json!({ "title": "Emma", "pages": 4.5 })).unwrap();
+
+        assert!(book.validate(&definition).is_err());
+
+        let book = Dynamic::try_from(json!({ "pages": 474 })).unwrap();
+
+        assert!(book.validate(&definition).is_err());
+    }
+}
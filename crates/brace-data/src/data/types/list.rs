@@ -1,8 +1,9 @@
 use std::ops::{Deref, DerefMut};
 
-use crate::constraint::Constraints;
+use crate::constraint::{Constrain, Constraints};
 use crate::data::definition::Definition;
 use crate::data::{Construct, Data, Define};
+use crate::schema::{Object, Schema};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct List<T>(Vec<T>, ListDefinition<T>)
@@ -108,6 +109,7 @@ where
     T: Data + 'static,
 {
     label: String,
+    version: u32,
     constraints: Constraints<List<T>>,
 }
 
@@ -137,6 +139,15 @@ where
         self.label = label.into();
     }
 
+    fn version(&self) -> u32 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u32) -> bool {
+        self.version = version;
+        true
+    }
+
     fn constraints(&self) -> &Constraints<Self::Data> {
         &self.constraints
     }
@@ -146,6 +157,24 @@ where
     }
 }
 
+impl<T> Schema for ListDefinition<T>
+where
+    T: Define,
+    T::Definition: Schema,
+{
+    fn schema(&self) -> Object {
+        let mut schema = Object::new();
+
+        schema.insert(String::from("type"), "array".into());
+        schema.insert(String::from("title"), self.label.as_str().into());
+        schema.insert(String::from("items"), T::define().schema().into());
+
+        self.constraints.describe(&mut schema);
+
+        schema
+    }
+}
+
 impl<T> Default for ListDefinition<T>
 where
     T: Data,
@@ -153,6 +182,7 @@ where
     fn default() -> Self {
         Self {
             label: String::from("List"),
+            version: 1,
             constraints: Constraints::new(),
         }
     }
@@ -160,9 +190,12 @@ where
 
 #[cfg(test)]
 mod tests {
+    use serde_json::json;
+
     use super::{List, ListDefinition};
     use crate::constraint::types::max_length::MaxLength;
     use crate::constraint::Validate;
+    use crate::schema::Schema;
     use crate::{Construct, Data, Define, Definition, Text};
 
     #[test]
@@ -218,4 +251,26 @@ mod tests {
                 .with_constraint(MaxLength(2))
         );
     }
+
+    #[test]
+    fn test_list_definition_schema() {
+        let definition = List::<Text>::define()
+            .with_label("Tags")
+            .with_constraint(MaxLength(2));
+
+        assert_eq!(
+            definition.schema(),
+            *json!({
+                "type": "array",
+                "title": "Tags",
+                "items": {
+                    "type": "string",
+                    "title": "Text",
+                },
+                "maxItems": 2,
+            })
+            .as_object()
+            .unwrap()
+        );
+    }
 }
@@ -1,2 +1,3 @@
+pub mod dynamic;
 pub mod list;
 pub mod text;
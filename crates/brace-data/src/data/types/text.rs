@@ -1,6 +1,7 @@
-use crate::constraint::Constraints;
+use crate::constraint::{Constrain, Constraints};
 use crate::data::definition::Definition;
 use crate::data::{Construct, Data, Define};
+use crate::schema::{Object, Schema};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Text(pub(crate) String, TextDefinition);
@@ -13,6 +14,10 @@ impl Text {
         Self(value.into(), TextDefinition::default())
     }
 
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
     pub fn len(&self) -> usize {
         self.0.len()
     }
@@ -62,6 +67,7 @@ impl From<String> for Text {
 #[derive(Clone, Debug, PartialEq)]
 pub struct TextDefinition {
     label: String,
+    version: u32,
     constraints: Constraints<Text>,
 }
 
@@ -85,6 +91,15 @@ impl Definition for TextDefinition {
         self.label = label.into();
     }
 
+    fn version(&self) -> u32 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u32) -> bool {
+        self.version = version;
+        true
+    }
+
     fn constraints(&self) -> &Constraints<Self::Data> {
         &self.constraints
     }
@@ -94,10 +109,24 @@ impl Definition for TextDefinition {
     }
 }
 
+impl Schema for TextDefinition {
+    fn schema(&self) -> Object {
+        let mut schema = Object::new();
+
+        schema.insert(String::from("type"), "string".into());
+        schema.insert(String::from("title"), self.label.as_str().into());
+
+        self.constraints.describe(&mut schema);
+
+        schema
+    }
+}
+
 impl Default for TextDefinition {
     fn default() -> Self {
         Self {
             label: String::from("Text"),
+            version: 1,
             constraints: Constraints::new(),
         }
     }
@@ -105,10 +134,14 @@ impl Default for TextDefinition {
 
 #[cfg(test)]
 mod tests {
+    use serde_json::json;
+
     use super::{Text, TextDefinition};
     use crate::constraint::types::max_length::MaxLength;
     use crate::constraint::types::min_length::MinLength;
+    use crate::constraint::types::pattern::Pattern;
     use crate::constraint::Validate;
+    use crate::schema::Schema;
     use crate::{Construct, Data, Define, Definition};
 
     #[test]
@@ -169,4 +202,24 @@ mod tests {
                 .with_constraint(MaxLength(3))
         );
     }
+
+    #[test]
+    fn test_text_definition_schema() {
+        let definition = Text::define()
+            .with_label("Phone")
+            .with_constraint(MinLength(12))
+            .with_constraint(Pattern::new("[0-9]{3}-[0-9]{3}-[0-9]{4}").unwrap());
+
+        assert_eq!(
+            definition.schema(),
+            *json!({
+                "type": "string",
+                "title": "Phone",
+                "minLength": 12,
+                "pattern": "[0-9]{3}-[0-9]{3}-[0-9]{4}",
+            })
+            .as_object()
+            .unwrap()
+        );
+    }
 }
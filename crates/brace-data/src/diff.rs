@@ -0,0 +1,448 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+use std::vec::IntoIter;
+
+use crate::constraint::{Constraint, Constraints};
+use crate::{
+    Data, Definition, Items, MaxItems, MaxLength, MinItems, MinLength, Properties, Required,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Compatibility {
+    Compatible,
+    Breaking,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Change {
+    Label {
+        from: String,
+        to: String,
+    },
+    Version {
+        from: u32,
+        to: u32,
+    },
+    Added(String),
+    Removed(String),
+    Changed {
+        from: String,
+        to: String,
+        compatibility: Compatibility,
+    },
+    FieldAdded(String),
+    FieldRemoved(String),
+    Field(String, Vec<Change>),
+    Items(Vec<Change>),
+}
+
+impl Change {
+    pub fn compatibility(&self) -> Compatibility {
+        match self {
+            Change::Added(_) => Compatibility::Breaking,
+            Change::Changed { compatibility, .. } => *compatibility,
+            Change::Field(_, changes) | Change::Items(changes) => compatibility(changes),
+            _ => Compatibility::Compatible,
+        }
+    }
+
+    pub fn is_breaking(&self) -> bool {
+        self.compatibility() == Compatibility::Breaking
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Change::Label { from, to } => write!(f, "label changed from {:?} to {:?}", from, to),
+            Change::Version { from, to } => write!(f, "version changed from {} to {}", from, to),
+            Change::Added(constraint) => write!(f, "added constraint {}", constraint),
+            Change::Removed(constraint) => write!(f, "removed constraint {}", constraint),
+            Change::Changed { from, to, .. } => {
+                write!(f, "changed constraint {} to {}", from, to)
+            }
+            Change::FieldAdded(name) => write!(f, "added field {}", name),
+            Change::FieldRemoved(name) => write!(f, "removed field {}", name),
+            Change::Field(name, changes) => write!(f, "field {}: {}", name, Joined(changes)),
+            Change::Items(changes) => write!(f, "items: {}", Joined(changes)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Diff(Vec<Change>);
+
+impl Diff {
+    pub fn new<D>(from: &D, to: &D) -> Self
+    where
+        D: Definition,
+        D::Data: 'static,
+    {
+        Self(definitions(from, to))
+    }
+
+    pub fn changes(&self) -> &[Change] {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn compatibility(&self) -> Compatibility {
+        compatibility(&self.0)
+    }
+
+    pub fn is_breaking(&self) -> bool {
+        self.compatibility() == Compatibility::Breaking
+    }
+}
+
+impl Display for Diff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for change in &self.0 {
+            writeln!(f, "{}", change)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl IntoIterator for Diff {
+    type Item = Change;
+    type IntoIter = IntoIter<Change>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+struct Joined<'a>(&'a [Change]);
+
+impl Display for Joined<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, change) in self.0.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{}", change)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn compatibility(changes: &[Change]) -> Compatibility {
+    changes
+        .iter()
+        .map(Change::compatibility)
+        .max()
+        .unwrap_or(Compatibility::Compatible)
+}
+
+fn definitions<D>(from: &D, to: &D) -> Vec<Change>
+where
+    D: Definition,
+    D::Data: 'static,
+{
+    let mut changes = Vec::new();
+
+    if from.label() != to.label() {
+        changes.push(Change::Label {
+            from: from.label().to_owned(),
+            to: to.label().to_owned(),
+        });
+    }
+
+    if from.version() != to.version() {
+        changes.push(Change::Version {
+            from: from.version(),
+            to: to.version(),
+        });
+    }
+
+    changes.extend(constraints(from.constraints(), to.constraints()));
+    changes
+}
+
+fn constraints<T>(from: &Constraints<T>, to: &Constraints<T>) -> Vec<Change>
+where
+    T: Data + 'static,
+{
+    let mut changes = Vec::new();
+    let mut entries = from.iter().collect::<Vec<_>>();
+
+    entries.sort_by_key(|(_, constraint)| format!("{:?}", constraint));
+
+    for (id, old) in entries {
+        match to.get_dyn(id) {
+            Some(new) => changes.extend(constraint(&**old, new)),
+            None => changes.push(Change::Removed(format!("{:?}", old))),
+        }
+    }
+
+    let mut added = to
+        .iter()
+        .filter(|(id, _)| from.get_dyn(id).is_none())
+        .map(|(_, constraint)| Change::Added(format!("{:?}", constraint)))
+        .collect::<Vec<_>>();
+
+    added.sort_by_key(|change| change.to_string());
+    changes.extend(added);
+    changes
+}
+
+fn constraint<T>(from: &dyn Constraint<T>, to: &dyn Constraint<T>) -> Vec<Change>
+where
+    T: Data,
+{
+    if from.eq_any(to.as_any()) {
+        return Vec::new();
+    }
+
+    let (old, new) = (from.as_any(), to.as_any());
+
+    if let (Some(old), Some(new)) = (old.downcast_ref::<Items>(), new.downcast_ref::<Items>()) {
+        return vec![Change::Items(definitions(&old.0, &new.0))];
+    }
+
+    if let (Some(old), Some(new)) = (
+        old.downcast_ref::<Properties>(),
+        new.downcast_ref::<Properties>(),
+    ) {
+        return properties(old, new);
+    }
+
+    let compatibility = if let (Some(old), Some(new)) = (
+        old.downcast_ref::<MinLength>(),
+        new.downcast_ref::<MinLength>(),
+    ) {
+        tightened(new.0.cmp(&old.0))
+    } else if let (Some(old), Some(new)) = (
+        old.downcast_ref::<MaxLength>(),
+        new.downcast_ref::<MaxLength>(),
+    ) {
+        tightened(old.0.cmp(&new.0))
+    } else if let (Some(old), Some(new)) = (
+        old.downcast_ref::<MinItems>(),
+        new.downcast_ref::<MinItems>(),
+    ) {
+        tightened(new.0.cmp(&old.0))
+    } else if let (Some(old), Some(new)) = (
+        old.downcast_ref::<MaxItems>(),
+        new.downcast_ref::<MaxItems>(),
+    ) {
+        tightened(old.0.cmp(&new.0))
+    } else if let (Some(old), Some(new)) = (
+        old.downcast_ref::<Required>(),
+        new.downcast_ref::<Required>(),
+    ) {
+        if new.0.iter().all(|name| old.0.contains(name)) {
+            Compatibility::Compatible
+        } else {
+            Compatibility::Breaking
+        }
+    } else {
+        Compatibility::Breaking
+    };
+
+    vec![Change::Changed {
+        from: format!("{:?}", from),
+        to: format!("{:?}", to),
+        compatibility,
+    }]
+}
+
+fn properties(from: &Properties, to: &Properties) -> Vec<Change> {
+    let mut changes = Vec::new();
+
+    for (name, old) in from {
+        match to.get(name) {
+            Some(new) => {
+                let field = definitions(old, new);
+
+                if !field.is_empty() {
+                    changes.push(Change::Field(name.clone(), field));
+                }
+            }
+            None => changes.push(Change::FieldRemoved(name.clone())),
+        }
+    }
+
+    for (name, _) in to {
+        if from.get(name).is_none() {
+            changes.push(Change::FieldAdded(name.clone()));
+        }
+    }
+
+    changes
+}
+
+fn tightened(ordering: Ordering) -> Compatibility {
+    match ordering {
+        Ordering::Greater => Compatibility::Breaking,
+        _ => Compatibility::Compatible,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Change, Compatibility, Diff};
+    use crate::{
+        Define, Definition, Dynamic, DynamicDefinition, Items, Kind, MaxLength, MinLength, Pattern,
+        Properties, Required, Text,
+    };
+
+    fn article(fields: &[(&str, usize)], required: &[&str]) -> DynamicDefinition {
+        let mut properties = Properties::new();
+
+        for (name, max) in fields {
+            properties.insert(*name, Dynamic::define().with_constraint(MaxLength(*max)));
+        }
+
+        Dynamic::define()
+            .with_label("Article")
+            .with_constraint(Kind::Object)
+            .with_constraint(properties)
+            .with_constraint(Required(
+                required.iter().map(|name| name.to_string()).collect(),
+            ))
+    }
+
+    #[test]
+    fn test_diff_unchanged() {
+        let definition = Text::define().with_constraint(MinLength(1));
+        let diff = Diff::new(&definition, &definition.clone());
+
+        assert!(diff.is_empty());
+        assert_eq!(diff.compatibility(), Compatibility::Compatible);
+    }
+
+    #[test]
+    fn test_diff_label() {
+        let diff = Diff::new(
+            &Text::define().with_label("Title"),
+            &Text::define().with_label("Headline").with_version(2),
+        );
+
+        assert_eq!(
+            diff.changes(),
+            &[
+                Change::Label {
+                    from: String::from("Title"),
+                    to: String::from("Headline"),
+                },
+                Change::Version { from: 1, to: 2 },
+            ]
+        );
+        assert!(!diff.is_breaking());
+    }
+
+    #[test]
+    fn test_diff_constraints() {
+        let from = Text::define().with_constraint(MinLength(1));
+
+        let diff = Diff::new(&from, &from.clone().with_constraint(MaxLength(5)));
+
+        assert_eq!(
+            diff.changes(),
+            &[Change::Added(String::from("MaxLength(5)"))]
+        );
+        assert!(diff.is_breaking());
+
+        let diff = Diff::new(&from, &Text::define());
+
+        assert_eq!(
+            diff.changes(),
+            &[Change::Removed(String::from("MinLength(1)"))]
+        );
+        assert!(!diff.is_breaking());
+    }
+
+    #[test]
+    fn test_diff_tightened() {
+        let from = Text::define()
+            .with_constraint(MinLength(2))
+            .with_constraint(MaxLength(8));
+
+        let diff = Diff::new(
+            &from,
+            &Text::define()
+                .with_constraint(MinLength(1))
+                .with_constraint(MaxLength(9)),
+        );
+
+        assert_eq!(diff.changes().len(), 2);
+        assert!(!diff.is_breaking());
+
+        let diff = Diff::new(&from, &from.clone().with_constraint(MaxLength(5)));
+
+        assert_eq!(
+            diff.changes(),
+            &[Change::Changed {
+                from: String::from("MaxLength(8)"),
+                to: String::from("MaxLength(5)"),
+                compatibility: Compatibility::Breaking,
+            }]
+        );
+
+        let diff = Diff::new(
+            &Text::define().with_constraint(Pattern::new("[a-z]+").unwrap()),
+            &Text::define().with_constraint(Pattern::new("[a-z]*").unwrap()),
+        );
+
+        assert!(diff.is_breaking());
+    }
+
+    #[test]
+    fn test_diff_fields() {
+        let from = article(&[("title", 20), ("body", 100)], &["title"]);
+
+        let diff = Diff::new(
+            &from,
+            &article(&[("title", 20), ("body", 100), ("slug", 20)], &["title"]),
+        );
+
+        assert_eq!(diff.changes(), &[Change::FieldAdded(String::from("slug"))]);
+        assert!(!diff.is_breaking());
+
+        let diff = Diff::new(
+            &from,
+            &article(&[("title", 10), ("body", 100)], &["title", "body"]),
+        );
+
+        assert_eq!(diff.changes().len(), 2);
+        assert!(diff.changes().contains(&Change::Field(
+            String::from("title"),
+            vec![Change::Changed {
+                from: String::from("MaxLength(20)"),
+                to: String::from("MaxLength(10)"),
+                compatibility: Compatibility::Breaking,
+            }]
+        )));
+        assert!(diff.is_breaking());
+
+        let diff = Diff::new(&from, &article(&[("title", 20)], &[]));
+
+        assert!(diff
+            .changes()
+            .contains(&Change::FieldRemoved(String::from("body"))));
+        assert!(!diff.is_breaking());
+    }
+
+    #[test]
+    fn test_diff_items() {
+        let from = Dynamic::define()
+            .with_constraint(Items(Dynamic::define().with_constraint(MinLength(1))));
+        let to = Dynamic::define()
+            .with_constraint(Items(Dynamic::define().with_constraint(MinLength(2))));
+        let diff = Diff::new(&from, &to);
+
+        assert!(diff.is_breaking());
+        assert_eq!(
+            diff.to_string(),
+            "items: changed constraint MinLength(1) to MinLength(2)\n"
+        );
+    }
+}
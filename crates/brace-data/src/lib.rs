@@ -1,13 +1,27 @@
 pub use self::constraint::types::and::And;
+pub use self::constraint::types::items::Items;
+pub use self::constraint::types::kind::Kind;
+pub use self::constraint::types::max_items::MaxItems;
 pub use self::constraint::types::max_length::MaxLength;
+pub use self::constraint::types::min_items::MinItems;
 pub use self::constraint::types::min_length::MinLength;
 pub use self::constraint::types::or::Or;
 pub use self::constraint::types::pattern::Pattern;
+pub use self::constraint::types::properties::Properties;
+pub use self::constraint::types::required::Required;
 pub use self::data::definition::Definition;
+pub use self::data::registry::Registry;
+pub use self::data::types::dynamic::{Dynamic, DynamicDefinition, Value};
 pub use self::data::types::list::{List, ListDefinition};
 pub use self::data::types::text::{Text, TextDefinition};
 pub use self::data::{Construct, Data, Define};
+pub use self::diff::Diff;
+pub use self::migration::Migrator;
+pub use self::schema::Schema;
 
 pub mod constraint;
 pub mod data;
+pub mod diff;
+pub mod migration;
+pub mod schema;
 pub mod util;
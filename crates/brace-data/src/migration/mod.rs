@@ -0,0 +1,339 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Debug};
+
+use crate::constraint::Validate;
+use crate::{Data, Definition};
+
+pub use self::error::Error;
+pub use self::report::{Failed, Invalid, Report};
+
+pub mod error;
+pub mod report;
+
+type Transform<T> = Box<dyn Fn(T, &<T as Data>::Definition) -> T>;
+
+pub struct Migrator<T>(BTreeMap<u32, Migration<T>>)
+where
+    T: Data;
+
+struct Migration<T>
+where
+    T: Data,
+{
+    definition: T::Definition,
+    transform: Transform<T>,
+}
+
+impl<T> Migrator<T>
+where
+    T: Data,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register<F>(&mut self, definition: T::Definition, transform: F) -> Result<(), Error>
+    where
+        F: Fn(T, &T::Definition) -> T + 'static,
+    {
+        let version = definition.version();
+
+        if self.0.contains_key(&version) {
+            return Err(Error::message(format!(
+                "migration to version {} is already registered",
+                version
+            )));
+        }
+
+        self.0.insert(
+            version,
+            Migration {
+                definition,
+                transform: Box::new(transform),
+            },
+        );
+
+        Ok(())
+    }
+
+    pub fn with_migration<F>(
+        mut self,
+        definition: T::Definition,
+        transform: F,
+    ) -> Result<Self, Error>
+    where
+        F: Fn(T, &T::Definition) -> T + 'static,
+    {
+        self.register(definition, transform)?;
+
+        Ok(self)
+    }
+
+    pub fn version(&self) -> Option<u32> {
+        self.0.keys().next_back().copied()
+    }
+
+    pub fn definition(&self, version: u32) -> Option<&T::Definition> {
+        self.0.get(&version).map(|migration| &migration.definition)
+    }
+
+    pub fn migrate(&self, data: T) -> Result<T, Error> {
+        match self.version() {
+            Some(version) => self.migrate_to(data, version),
+            None => Ok(data),
+        }
+    }
+
+    pub fn migrate_to(&self, mut data: T, version: u32) -> Result<T, Error> {
+        let from = data.definition().version();
+
+        if from > version {
+            return Err(Error::message(format!(
+                "cannot migrate from version {} to earlier version {}",
+                from, version
+            )));
+        }
+
+        let mut current = from;
+
+        while current < version {
+            let next = current
+                .checked_add(1)
+                .ok_or_else(|| Error::message(format!("no migration after version {}", current)))?;
+
+            let migration = self.0.get(&next).ok_or_else(|| {
+                Error::message(format!("no migration from version {} to {}", current, next))
+            })?;
+
+            data = (migration.transform)(data, &migration.definition);
+
+            let produced = data.definition().version();
+
+            if produced != next {
+                return Err(Error::message(format!(
+                    "migration to version {} produced version {}",
+                    next, produced
+                )));
+            }
+
+            current = next;
+        }
+
+        Ok(data)
+    }
+
+    pub fn migrate_all<I>(&self, data: I) -> Report<T>
+    where
+        I: IntoIterator<Item = T>,
+        T: 'static,
+        T::Definition: Definition<Data = T>,
+    {
+        let definition = self.version().and_then(|version| self.definition(version));
+        let mut migrated = Vec::new();
+        let mut invalid = Vec::new();
+        let mut failed = Vec::new();
+
+        for (index, data) in data.into_iter().enumerate() {
+            let data = match self.migrate(data) {
+                Ok(data) => data,
+                Err(error) => {
+                    failed.push(Failed { index, error });
+                    continue;
+                }
+            };
+
+            if let Some(definition) = definition {
+                if let Err(error) = data.validate(definition) {
+                    invalid.push(Invalid { index, error });
+                }
+            }
+
+            migrated.push(data);
+        }
+
+        Report::new(migrated, invalid, failed)
+    }
+}
+
+impl<T> Debug for Migrator<T>
+where
+    T: Data,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.0.keys()).finish()
+    }
+}
+
+impl<T> Default for Migrator<T>
+where
+    T: Data,
+{
+    fn default() -> Self {
+        Self(BTreeMap::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use serde_json::json;
+
+    use super::Migrator;
+    use crate::{
+        Construct, Data, Define, Definition, Dynamic, DynamicDefinition, Kind, MaxLength,
+        Properties, Text, Value,
+    };
+
+    fn article(version: u32, field: &str, max: usize) -> DynamicDefinition {
+        let mut properties = Properties::new();
+
+        properties.insert(field, Dynamic::define().with_constraint(MaxLength(max)));
+
+        Dynamic::define()
+            .with_label("Article")
+            .with_version(version)
+            .with_constraint(Kind::Object)
+            .with_constraint(properties)
+    }
+
+    fn rename(
+        from: &'static str,
+        to: &'static str,
+    ) -> impl Fn(Dynamic, &DynamicDefinition) -> Dynamic {
+        move |data, definition| match data.into_value() {
+            Value::Object(mut object) => {
+                if let Some(value) = object.remove(from) {
+                    object.insert(String::from(to), value);
+                }
+
+                Dynamic::construct(object, definition.clone())
+            }
+            value => Dynamic::construct(value, definition.clone()),
+        }
+    }
+
+    #[test]
+    fn test_migrate_text() {
+        let migrator = Migrator::<Text>::new()
+            .with_migration(Text::define().with_version(2), |text, definition| {
+                Text::construct(text.as_str().to_uppercase(), definition.clone())
+            })
+            .unwrap();
+
+        let text = migrator.migrate(Text::new("hello")).unwrap();
+
+        assert_eq!(text.as_str(), "HELLO");
+        assert_eq!(text.definition().version(), 2);
+        assert_eq!(migrator.version(), Some(2));
+    }
+
+    #[test]
+    fn test_migrate_steps() {
+        let migrator = Migrator::new()
+            .with_migration(article(2, "headline", 20), rename("title", "headline"))
+            .unwrap()
+            .with_migration(article(3, "heading", 10), rename("headline", "heading"))
+            .unwrap();
+
+        let data = Dynamic::construct(
+            Dynamic::try_from(json!({ "title": "Hello" }))
+                .unwrap()
+                .into_value(),
+            article(1, "title", 20),
+        );
+
+        let data = migrator.migrate_to(data, 2).unwrap();
+
+        assert_eq!(data.definition().version(), 2);
+        assert_eq!(data, {
+            let json = Dynamic::try_from(json!({ "headline": "Hello" })).unwrap();
+            Dynamic::construct(json.into_value(), article(2, "headline", 20))
+        });
+
+        let data = migrator.migrate(data).unwrap();
+
+        assert_eq!(data.definition().version(), 3);
+        assert!(migrator.migrate_to(data, 1).is_err());
+    }
+
+    #[test]
+    fn test_migrate_missing() {
+        let migrator = Migrator::new()
+            .with_migration(article(3, "heading", 10), rename("title", "heading"))
+            .unwrap();
+        let data = Dynamic::construct(Value::Null, article(1, "title", 20));
+
+        assert_eq!(
+            migrator.migrate(data).unwrap_err().to_string(),
+            "no migration from version 1 to 2"
+        );
+    }
+
+    #[test]
+    fn test_migrate_all() {
+        let migrator = Migrator::new()
+            .with_migration(article(2, "heading", 10), rename("title", "heading"))
+            .unwrap();
+
+        let data = vec![
+            json!({ "title": "Hello" }),
+            json!({ "title": "Hello World!" }),
+            json!({ "title": "Goodbye" }),
+            json!({ "title": "Later" }),
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(index, json)| {
+            let json = Dynamic::try_from(json).unwrap();
+            let version = if index == 3 { 3 } else { 1 };
+
+            Dynamic::construct(json.into_value(), article(version, "title", 20))
+        });
+
+        let report = migrator.migrate_all(data);
+
+        assert!(!report.is_valid());
+        assert_eq!(report.data().len(), 3);
+        assert_eq!(report.invalid().len(), 1);
+        assert_eq!(report.invalid()[0].index, 1);
+        assert_eq!(report.failed().len(), 1);
+        assert_eq!(report.failed()[0].index, 3);
+    }
+
+    #[test]
+    fn test_migrate_duplicate() {
+        let migrator = Migrator::new()
+            .with_migration(article(2, "heading", 10), rename("title", "heading"))
+            .unwrap();
+
+        assert_eq!(
+            migrator
+                .with_migration(article(2, "headline", 20), rename("title", "headline"))
+                .unwrap_err()
+                .to_string(),
+            "migration to version 2 is already registered"
+        );
+    }
+
+    #[test]
+    fn test_migrate_version_mismatch() {
+        let migrator = Migrator::new()
+            .with_migration(article(2, "heading", 10), |data, _| data)
+            .unwrap();
+        let data = Dynamic::construct(Value::Null, article(1, "title", 20));
+
+        assert_eq!(
+            migrator.migrate(data).unwrap_err().to_string(),
+            "migration to version 2 produced version 1"
+        );
+    }
+
+    #[test]
+    fn test_migrate_max_version() {
+        let migrator = Migrator::<Text>::new();
+        let text = Text::construct("hello", Text::define().with_version(u32::MAX));
+
+        assert!(migrator.migrate_to(text, u32::MAX).is_ok());
+    }
+}
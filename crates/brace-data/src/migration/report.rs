@@ -0,0 +1,51 @@
+use super::Error;
+use crate::constraint::Error as ConstraintError;
+
+#[derive(Debug)]
+pub struct Report<T> {
+    data: Vec<T>,
+    invalid: Vec<Invalid>,
+    failed: Vec<Failed>,
+}
+
+impl<T> Report<T> {
+    pub(crate) fn new(data: Vec<T>, invalid: Vec<Invalid>, failed: Vec<Failed>) -> Self {
+        Self {
+            data,
+            invalid,
+            failed,
+        }
+    }
+
+    pub fn data(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn into_data(self) -> Vec<T> {
+        self.data
+    }
+
+    pub fn invalid(&self) -> &[Invalid] {
+        &self.invalid
+    }
+
+    pub fn failed(&self) -> &[Failed] {
+        &self.failed
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.invalid.is_empty() && self.failed.is_empty()
+    }
+}
+
+#[derive(Debug)]
+pub struct Invalid {
+    pub index: usize,
+    pub error: ConstraintError,
+}
+
+#[derive(Debug)]
+pub struct Failed {
+    pub index: usize,
+    pub error: Error,
+}
@@ -0,0 +1,34 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum Error {
+    Invalid(String),
+    Unsupported(String),
+}
+
+impl Error {
+    pub fn invalid<S>(message: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::Invalid(message.into())
+    }
+
+    pub fn unsupported<S>(message: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::Unsupported(message.into())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Invalid(msg) => write!(f, "{}", msg),
+            Error::Unsupported(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
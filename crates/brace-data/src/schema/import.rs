@@ -0,0 +1,406 @@
+use serde_json::Value;
+
+use crate::constraint::{Constraint, Constraints};
+use crate::data::types::dynamic::{Dynamic, DynamicDefinition};
+use crate::schema::Error;
+use crate::{
+    And, Definition, Items, Kind, MaxItems, MaxLength, MinItems, MinLength, Or, Pattern,
+    Properties, Required,
+};
+
+pub fn import(schema: &Value) -> Result<DynamicDefinition, Error> {
+    definition(schema, "#")
+}
+
+fn definition(schema: &Value, path: &str) -> Result<DynamicDefinition, Error> {
+    let mut definition = DynamicDefinition::new();
+
+    if let Value::Object(object) = schema {
+        if let Some(title) = object.get("title") {
+            definition.set_label(string(title, &format!("{}/title", path))?);
+        }
+    }
+
+    *definition.constraints_mut() = constraints(schema, path)?;
+
+    Ok(definition)
+}
+
+fn constraints(schema: &Value, path: &str) -> Result<Constraints<Dynamic>, Error> {
+    let object = match schema {
+        Value::Object(object) => object,
+        Value::Bool(true) => return Ok(Constraints::new()),
+        _ => {
+            return Err(Error::unsupported(format!(
+                "unsupported schema {} at {}",
+                schema, path
+            )))
+        }
+    };
+
+    let mut constraints = Constraints::new();
+
+    for (keyword, value) in object {
+        let path = format!("{}/{}", path, keyword);
+
+        match keyword.as_str() {
+            "$schema" | "$id" | "$comment" | "title" | "description" => {}
+            "type" => insert(&mut constraints, kind(value, &path)?, &path)?,
+            "minLength" => insert(&mut constraints, MinLength(length(value, &path)?), &path)?,
+            "maxLength" => insert(&mut constraints, MaxLength(length(value, &path)?), &path)?,
+            "minItems" => insert(&mut constraints, MinItems(length(value, &path)?), &path)?,
+            "maxItems" => insert(&mut constraints, MaxItems(length(value, &path)?), &path)?,
+            "pattern" => insert(&mut constraints, pattern(value, &path)?, &path)?,
+            "items" => insert(&mut constraints, Items(definition(value, &path)?), &path)?,
+            "properties" => insert(&mut constraints, properties(value, &path)?, &path)?,
+            "required" => insert(&mut constraints, required(value, &path)?, &path)?,
+            "anyOf" => insert(&mut constraints, any_of(value, &path)?, &path)?,
+            "allOf" => insert(&mut constraints, all_of(value, &path)?, &path)?,
+            _ => {
+                return Err(Error::unsupported(format!(
+                    "unsupported keyword `{}` at {}",
+                    keyword, path
+                )))
+            }
+        }
+    }
+
+    Ok(constraints)
+}
+
+fn insert<T>(constraints: &mut Constraints<Dynamic>, constraint: T, path: &str) -> Result<(), Error>
+where
+    T: Constraint<Dynamic> + 'static,
+{
+    if constraints.get::<T>().is_some() {
+        return Err(Error::unsupported(format!(
+            "unsupported duplicate constraint at {}",
+            path
+        )));
+    }
+
+    constraints.insert(constraint);
+
+    Ok(())
+}
+
+fn kind(value: &Value, path: &str) -> Result<Kind, Error> {
+    if value.is_array() {
+        return Err(Error::unsupported(format!(
+            "unsupported list of types at {}",
+            path
+        )));
+    }
+
+    match string(value, path)? {
+        "null" => Ok(Kind::Null),
+        "boolean" => Ok(Kind::Bool),
+        "number" => Ok(Kind::Number),
+        "integer" => Ok(Kind::Integer),
+        "string" => Ok(Kind::Text),
+        "array" => Ok(Kind::List),
+        "object" => Ok(Kind::Object),
+        kind => Err(Error::invalid(format!(
+            "invalid type `{}` at {}",
+            kind, path
+        ))),
+    }
+}
+
+fn length(value: &Value, path: &str) -> Result<usize, Error> {
+    value
+        .as_u64()
+        .map(|length| length as usize)
+        .ok_or_else(|| Error::invalid(format!("expected a non-negative integer at {}", path)))
+}
+
+fn pattern(value: &Value, path: &str) -> Result<Pattern, Error> {
+    Pattern::new(string(value, path)?)
+        .map_err(|err| Error::invalid(format!("invalid pattern at {}: {}", path, err)))
+}
+
+fn properties(value: &Value, path: &str) -> Result<Properties, Error> {
+    let object = value
+        .as_object()
+        .ok_or_else(|| Error::invalid(format!("expected an object at {}", path)))?;
+
+    let mut properties = Properties::new();
+
+    for (name, schema) in object {
+        properties.insert(
+            name.as_str(),
+            definition(schema, &format!("{}/{}", path, name))?,
+        );
+    }
+
+    Ok(properties)
+}
+
+fn required(value: &Value, path: &str) -> Result<Required, Error> {
+    let mut required = Vec::new();
+
+    for (index, name) in array(value, path)?.iter().enumerate() {
+        required.push(string(name, &format!("{}/{}", path, index))?.to_owned());
+    }
+
+    Ok(Required(required))
+}
+
+fn any_of(value: &Value, path: &str) -> Result<Or<Dynamic>, Error> {
+    Ok(Or::from(branches(value, path)?))
+}
+
+fn all_of(value: &Value, path: &str) -> Result<And<Dynamic>, Error> {
+    Ok(And::from(branches(value, path)?))
+}
+
+fn branches(value: &Value, path: &str) -> Result<Vec<Constraints<Dynamic>>, Error> {
+    array(value, path)?
+        .iter()
+        .enumerate()
+        .map(|(index, schema)| constraints(schema, &format!("{}/{}", path, index)))
+        .collect()
+}
+
+fn string<'a>(value: &'a Value, path: &str) -> Result<&'a str, Error> {
+    value
+        .as_str()
+        .ok_or_else(|| Error::invalid(format!("expected a string at {}", path)))
+}
+
+fn array<'a>(value: &'a Value, path: &str) -> Result<&'a Vec<Value>, Error> {
+    value
+        .as_array()
+        .ok_or_else(|| Error::invalid(format!("expected an array at {}", path)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use serde_json::json;
+
+    use super::import;
+    use crate::constraint::Validate;
+    use crate::schema::{Error, Schema};
+    use crate::{Define, Definition, Dynamic, Items, Kind, MaxLength, MinItems, MinLength, Or};
+
+    #[test]
+    fn test_import_text() {
+        let definition = import(&json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "string",
+            "title": "Name",
+            "minLength": 1,
+            "maxLength": 9,
+        }))
+        .unwrap();
+
+        assert_eq!(
+            definition,
+            Dynamic::define()
+                .with_label("Name")
+                .with_constraint(Kind::Text)
+                .with_constraint(MinLength(1))
+                .with_constraint(MaxLength(9))
+        );
+
+        assert!(Dynamic::new("hello").validate(&definition).is_ok());
+        assert!(Dynamic::new("").validate(&definition).is_err());
+        assert!(Dynamic::new(vec![]).validate(&definition).is_err());
+    }
+
+    #[test]
+    fn test_import_list() {
+        let definition = import(&json!({
+            "type": "array",
+            "maxItems": 2,
+            "items": {
+                "type": "string",
+                "pattern": "^[a-z]+$",
+            },
+        }))
+        .unwrap();
+
+        let valid = Dynamic::try_from(json!(["one", "two"])).unwrap();
+        let invalid = Dynamic::try_from(json!(["one", "2"])).unwrap();
+        let long = Dynamic::try_from(json!(["one", "two", "three"])).unwrap();
+
+        assert!(valid.validate(&definition).is_ok());
+        assert!(invalid.validate(&definition).is_err());
+        assert!(long.validate(&definition).is_err());
+    }
+
+    #[test]
+    fn test_import_any_of() {
+        let definition = import(&json!({
+            "anyOf": [
+                { "type": "string", "maxLength": 3 },
+                { "type": "array" },
+            ],
+        }))
+        .unwrap();
+
+        assert!(Dynamic::new("one").validate(&definition).is_ok());
+        assert!(Dynamic::new("three").validate(&definition).is_err());
+        assert!(Dynamic::new(vec![]).validate(&definition).is_ok());
+
+        let mut any = Or::new();
+
+        any.insert(MinLength(3));
+        any.insert(Items(Dynamic::define().with_constraint(Kind::Text)));
+
+        assert_eq!(
+            import(&json!({
+                "anyOf": [
+                    { "minLength": 3 },
+                    { "items": { "type": "string" } },
+                ],
+            }))
+            .unwrap(),
+            Dynamic::define().with_constraint(any)
+        );
+    }
+
+    #[test]
+    fn test_import_any_of_branches() {
+        let definition = import(&json!({
+            "anyOf": [
+                { "type": "string", "minLength": 2 },
+                { "type": "array", "minItems": 1 },
+            ],
+        }))
+        .unwrap();
+
+        assert!(Dynamic::new("one").validate(&definition).is_ok());
+        assert!(Dynamic::new("o").validate(&definition).is_err());
+        assert!(Dynamic::new(vec![Dynamic::new("o")])
+            .validate(&definition)
+            .is_ok());
+        assert!(Dynamic::new(vec![]).validate(&definition).is_err());
+        assert!(Dynamic::new(true).validate(&definition).is_err());
+    }
+
+    #[test]
+    fn test_import_length_items() {
+        let definition = import(&json!({ "minLength": 1, "minItems": 2 })).unwrap();
+
+        assert_eq!(
+            definition,
+            Dynamic::define()
+                .with_constraint(MinLength(1))
+                .with_constraint(MinItems(2))
+        );
+        assert!(Dynamic::new("a").validate(&definition).is_ok());
+        assert!(Dynamic::new("").validate(&definition).is_err());
+        assert!(Dynamic::new(vec![Dynamic::new("a")])
+            .validate(&definition)
+            .is_err());
+    }
+
+    #[test]
+    fn test_import_all_of() {
+        let definition = import(&json!({
+            "allOf": [
+                { "type": "string" },
+                { "minLength": 2, "maxLength": 3 },
+            ],
+        }))
+        .unwrap();
+
+        assert!(Dynamic::new("one").validate(&definition).is_ok());
+        assert!(Dynamic::new("three").validate(&definition).is_err());
+        assert!(Dynamic::new(vec![]).validate(&definition).is_err());
+    }
+
+    #[test]
+    fn test_import_object() {
+        let definition = import(&json!({
+            "type": "object",
+            "title": "Book",
+            "properties": {
+                "title": { "type": "string", "minLength": 1 },
+                "pages": { "type": "integer" },
+                "draft": { "type": "boolean" },
+                "editor": { "type": "null" },
+            },
+            "required": ["title"],
+        }))
+        .unwrap();
+
+        let valid = Dynamic::try_from(json!({
+            "title": "Emma",
+            "pages": 474,
+            "draft": false,
+            "editor": null,
+        }))
+        .unwrap();
+
+        assert!(valid.validate(&definition).is_ok());
+
+        let invalid = Dynamic::try_from(json!({ "pages": 474 })).unwrap();
+
+        assert!(invalid.validate(&definition).is_err());
+
+        let invalid = Dynamic::try_from(json!({ "title": "Emma", "draft": 0 })).unwrap();
+
+        assert!(invalid.validate(&definition).is_err());
+    }
+
+    #[test]
+    fn test_import_export() {
+        let schema = json!({
+            "type": "array",
+            "title": "Tags",
+            "maxItems": 2,
+            "items": {
+                "type": "string",
+                "title": "Tag",
+                "minLength": 3,
+            },
+        });
+
+        assert_eq!(
+            serde_json::Value::Object(import(&schema).unwrap().schema()),
+            schema
+        );
+    }
+
+    #[test]
+    fn test_import_unsupported() {
+        let error = import(&json!({
+            "type": "array",
+            "items": { "type": "string", "format": "email" },
+        }))
+        .unwrap_err();
+
+        assert!(matches!(error, Error::Unsupported(_)));
+        assert_eq!(
+            error.to_string(),
+            "unsupported keyword `format` at #/items/format"
+        );
+
+        let error = import(&json!({ "type": ["string", "null"] })).unwrap_err();
+
+        assert_eq!(error.to_string(), "unsupported list of types at #/type");
+    }
+
+    #[test]
+    fn test_import_invalid() {
+        let error = import(&json!({ "minLength": -1 })).unwrap_err();
+
+        assert!(matches!(error, Error::Invalid(_)));
+        assert_eq!(
+            error.to_string(),
+            "expected a non-negative integer at #/minLength"
+        );
+
+        let error = import(&json!({ "pattern": "[" })).unwrap_err();
+
+        assert!(matches!(error, Error::Invalid(_)));
+
+        let error = import(&json!({ "type": "text" })).unwrap_err();
+
+        assert_eq!(error.to_string(), "invalid type `text` at #/type");
+    }
+}
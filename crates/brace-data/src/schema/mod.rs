@@ -0,0 +1,51 @@
+use serde_json::{Map, Value};
+
+pub use self::error::Error;
+pub use self::import::import;
+
+pub mod error;
+pub mod import;
+
+pub const DRAFT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+pub type Object = Map<String, Value>;
+
+pub trait Schema {
+    fn schema(&self) -> Object;
+
+    fn json_schema(&self) -> Value {
+        let mut document = Object::new();
+
+        document.insert(String::from("$schema"), Value::from(DRAFT));
+        document.extend(self.schema());
+
+        Value::Object(document)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::Schema;
+    use crate::{Define, Definition, MaxLength, MinLength, Text};
+
+    #[test]
+    fn test_json_schema() {
+        let definition = Text::define()
+            .with_label("Name")
+            .with_constraint(MinLength(1))
+            .with_constraint(MaxLength(9));
+
+        assert_eq!(
+            definition.json_schema(),
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "type": "string",
+                "title": "Name",
+                "minLength": 1,
+                "maxLength": 9,
+            })
+        );
+    }
+}